@@ -1,9 +1,9 @@
 // Import from `core` instead of from `std` since we are in no-std mode
-use core::result::Result;
+use core::{convert::TryFrom, result::Result};
 
 // Import heap related library from `alloc`
 // https://doc.rust-lang.org/alloc/index.html
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
 // Import CKB syscalls and structures
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
@@ -18,10 +18,20 @@ use ckb_std::{
 };
 
 use crate::error::Error;
-use protocol::{axon, Cursor};
+use protocol::{
+    axon, bytes_to_u128, bytes_to_u32, bytes_to_u64, checkpoint::Checkpoint, mode, sudt,
+    withdrawal, Cursor,
+};
+
+#[allow(clippy::upper_case_acronyms)]
+enum MODE {
+    ADMIN,
+    CHECKPOINT,
+    RECONFIGURE,
+}
 
 fn get_info_by_type_hash(
-    type_hash: &Vec<u8>,
+    type_hash: &[u8],
     source: Source,
 ) -> Result<(u64, axon::CheckpointLockCellData), Error> {
     let mut capacity = 0u64;
@@ -41,7 +51,9 @@ fn get_info_by_type_hash(
             }
             match load_cell_data(i, source) {
                 Ok(value) => {
-                    celldata = Some(axon::CheckpointLockCellData::from(Cursor::from(value)))
+                    let value = axon::CheckpointLockCellData::from(Cursor::from(value));
+                    value.verify()?;
+                    celldata = Some(value)
                 }
                 Err(err) => return Err(Error::from(err)),
             }
@@ -54,39 +66,190 @@ fn get_info_by_type_hash(
     Ok((capacity, celldata.unwrap()))
 }
 
-fn get_sudt_by_type_hash(type_hash: &Vec<u8>, source: Source) -> Result<u128, Error> {
-    let mut sudt = 0u128;
-    QueryIter::new(load_cell_type_hash, source)
+fn get_stake_data_from_celldeps(stake_type_hash: &[u8]) -> Result<axon::StakeLockCellData, Error> {
+    let mut stake_data = None;
+    QueryIter::new(load_cell_type_hash, Source::CellDep)
         .enumerate()
-        .map(|(i, cell_type_hash)| {
-            if cell_type_hash.unwrap_or([0u8; 32]) == type_hash[..] {
-                match load_cell_data(i, source) {
-                    Ok(value) => {
-                        // check uint128_t format
-                        if value.len() < 16 {
-                            return Err(Error::BadSudtDataFormat);
-                        }
-                        sudt += bytes_to_u128(&value[..16].to_vec());
-                    }
-                    Err(err) => return Err(Error::from(err)),
+        .map(|(i, type_hash)| {
+            if type_hash.unwrap_or([0u8; 32])[..] != stake_type_hash[..] {
+                return Ok(());
+            }
+            if stake_data.is_some() {
+                return Err(Error::StakeDataError);
+            }
+            match load_cell_data(i, Source::CellDep) {
+                Ok(value) => {
+                    let value = axon::StakeLockCellData::from(Cursor::from(value));
+                    value.verify()?;
+                    stake_data = Some(value)
                 }
+                Err(_) => return Err(Error::StakeDataError),
             }
             Ok(())
         })
         .collect::<Result<Vec<_>, _>>()?;
-    Ok(sudt)
+    if stake_data.is_none() {
+        return Err(Error::StakeDataEmpty);
+    }
+    Ok(stake_data.unwrap())
+}
+
+// bitmap marks validators from the most significant bit of its first byte
+fn get_signers_by_bitmap<'a>(
+    validators: &'a [axon::StakeInfo],
+    bitmap: &[u8],
+) -> Result<Vec<&'a axon::StakeInfo>, Error> {
+    let mut signers = Vec::new();
+    for (i, byte) in bitmap.iter().enumerate() {
+        for j in 0..8 {
+            if byte & (0x80 >> j) == 0 {
+                continue;
+            }
+            match validators.get(i * 8 + j) {
+                Some(validator) => signers.push(validator),
+                None => return Err(Error::BitmapOutOfRange),
+            }
+        }
+    }
+    Ok(signers)
+}
+
+// split `reward` among signers in proportion to their weight, and the first signer takes the
+// dust of rounding
+fn get_signer_rewards<'a>(
+    stake_data: &axon::StakeLockCellData,
+    signers: &[&'a axon::StakeInfo],
+    reward: u128,
+    era: u64,
+) -> Result<Vec<(&'a axon::StakeInfo, u128)>, Error> {
+    let weights = signers
+        .iter()
+        .map(|signer| protocol::stake::get_validator_weight(stake_data, signer, era))
+        .collect::<Result<Vec<_>, _>>()?;
+    let total_weight = weights
+        .iter()
+        .fold(0u128, |total, weight| total.saturating_add(*weight));
+    let mut signer_rewards = signers
+        .iter()
+        .zip(weights)
+        .map(|(signer, weight)| {
+            (
+                *signer,
+                protocol::stake::mul_div(reward, weight, total_weight),
+            )
+        })
+        .collect::<Vec<_>>();
+    let dust = signer_rewards
+        .iter()
+        .fold(reward, |dust, (_, signer_reward)| dust - signer_reward);
+    if let Some((_, signer_reward)) = signer_rewards.first_mut() {
+        *signer_reward += dust;
+    }
+    Ok(signer_rewards)
+}
+
+// parameters which can only be changed by applying pending params
+fn is_params_unchanged(
+    input_checkpoint_data: &axon::CheckpointLockCellData,
+    output_checkpoint_data: &axon::CheckpointLockCellData,
+) -> bool {
+    input_checkpoint_data.unlock_period() == output_checkpoint_data.unlock_period()
+        && input_checkpoint_data.base_reward() == output_checkpoint_data.base_reward()
+        && input_checkpoint_data.half_period() == output_checkpoint_data.half_period()
 }
 
-fn bytes_to_u128(bytes: &Vec<u8>) -> u128 {
-    let mut array: [u8; 16] = [0u8; 16];
-    array.copy_from_slice(bytes.as_slice());
-    u128::from_le_bytes(array)
+fn get_pending_params(
+    checkpoint_data: &axon::CheckpointLockCellData,
+) -> Result<Option<Vec<u8>>, Error> {
+    let pending_params = checkpoint_data
+        .pending_params()
+        .map(|pending_params| Vec::<u8>::try_from(pending_params.cursor))
+        .transpose()?;
+    Ok(pending_params)
 }
 
-fn bytes_to_u64(bytes: &Vec<u8>) -> u64 {
-    let mut array: [u8; 8] = [0u8; 8];
-    array.copy_from_slice(bytes.as_slice());
-    u64::from_le_bytes(array)
+fn get_withdrawal_expiry(
+    checkpoint_data: &axon::CheckpointLockCellData,
+) -> Result<Option<Vec<u8>>, Error> {
+    let withdrawal_expiry = checkpoint_data
+        .withdrawal_expiry()
+        .map(|expiry| Vec::<u8>::try_from(expiry.cursor))
+        .transpose()?;
+    Ok(withdrawal_expiry)
+}
+
+// reward of `period` follows the reward schedule, and never exceeds the remaining supply
+fn get_period_reward(
+    checkpoint_data: &axon::CheckpointLockCellData,
+    period: u64,
+) -> Result<u128, Error> {
+    let reward_schedule = checkpoint_data.reward_schedule();
+    let base_reward = bytes_to_u128(&checkpoint_data.base_reward());
+    let reward = match reward_schedule.version() {
+        // base_reward / 2^⌊period/half_period⌋, which becomes zero once the divisor no longer
+        // fits in u128
+        0 => {
+            let half_period = bytes_to_u64(&checkpoint_data.half_period());
+            if half_period == 0 {
+                return Err(Error::CheckpointDataError);
+            }
+            u32::try_from(period / half_period)
+                .ok()
+                .and_then(|halvings| 2u128.checked_pow(halvings))
+                .map_or(0, |divisor| base_reward / divisor)
+        }
+        // base_reward - period * decay_reward, which becomes zero once the decay exceeds
+        // base_reward
+        1 => {
+            let decay_reward = bytes_to_u128(&reward_schedule.decay_reward());
+            decay_reward
+                .checked_mul(period as u128)
+                .and_then(|decay| base_reward.checked_sub(decay))
+                .unwrap_or(0)
+        }
+        _ => return Err(Error::BadRewardSchedule),
+    };
+    let reward = reward.max(bytes_to_u128(&reward_schedule.tail_reward()));
+    let remaining_supply = bytes_to_u128(&reward_schedule.supply_cap())
+        .saturating_sub(bytes_to_u128(&checkpoint_data.minted_amount()));
+    Ok(reward.min(remaining_supply))
+}
+
+// check AT amount of Withdrawal AT cells of `identity` increases by `reward`, and the reward is
+// locked until `withdrawal_period`, while AT of other periods is kept as it is, so the reward can
+// be appended to an existing Withdrawal AT cell of `withdrawal_period`
+fn check_withdrawal_reward(
+    checkpoint_data: &axon::CheckpointLockCellData,
+    admin_identity: &[u8],
+    checkpoint_type_hash: &[u8],
+    identity: &[u8],
+    reward: u128,
+    withdrawal_period: u64,
+) -> Result<(), Error> {
+    let sudt_type_hash = checkpoint_data.sudt_type_hash();
+    let withdrawal_expiry = get_withdrawal_expiry(checkpoint_data)?;
+    let withdrawal_lock = withdrawal::lock_script(
+        &checkpoint_data.withdrawal_lock_code_hash(),
+        &withdrawal::lock_args(
+            admin_identity,
+            checkpoint_type_hash,
+            Some(identity),
+            withdrawal_expiry.as_deref(),
+        ),
+    );
+    let mut input_buckets =
+        withdrawal::get_withdrawal_buckets(&withdrawal_lock, &sudt_type_hash, Source::Input)?;
+    let mut output_buckets =
+        withdrawal::get_withdrawal_buckets(&withdrawal_lock, &sudt_type_hash, Source::Output)?;
+    let input_withdrawal_amount = input_buckets.remove(&withdrawal_period).unwrap_or(0);
+    let output_withdrawal_amount = output_buckets.remove(&withdrawal_period).unwrap_or(0);
+    if input_buckets != output_buckets {
+        return Err(Error::BadWithdrawalPeriod);
+    }
+    if output_withdrawal_amount.checked_sub(input_withdrawal_amount) != Some(reward) {
+        return Err(Error::WithdrawalAmountMismatch);
+    }
+    Ok(())
 }
 
 pub fn main() -> Result<(), Error> {
@@ -94,6 +257,7 @@ pub fn main() -> Result<(), Error> {
     let args: Bytes = script.args().unpack();
 
     let checkpoint_args: axon::CheckpointLockArgs = Cursor::from(args.to_vec()).into();
+    checkpoint_args.verify()?;
     let admin_identity = checkpoint_args.admin_identity();
     let type_id_hash = checkpoint_args.type_id_hash();
 
@@ -105,95 +269,265 @@ pub fn main() -> Result<(), Error> {
     if input_checkpoint_capacity != output_checkpoint_capacity {
         return Err(Error::CheckpointCapacityMismatch);
     }
+    // period_interval and era_period are not pending params, since L2 block_number and era are
+    // derived from period by them from genesis, which changing them would jump or roll back
     if input_checkpoint_data.version() != output_checkpoint_data.version()
         || input_checkpoint_data.period_interval() != output_checkpoint_data.period_interval()
         || input_checkpoint_data.era_period() != output_checkpoint_data.era_period()
-        || input_checkpoint_data.base_reward() != output_checkpoint_data.base_reward()
-        || input_checkpoint_data.half_period() != output_checkpoint_data.half_period()
         || input_checkpoint_data.sudt_type_hash() != output_checkpoint_data.sudt_type_hash()
         || input_checkpoint_data.stake_type_hash() != output_checkpoint_data.stake_type_hash()
         || input_checkpoint_data.withdrawal_lock_code_hash()
             != output_checkpoint_data.withdrawal_lock_code_hash()
+        || Vec::<u8>::try_from(input_checkpoint_data.reward_schedule().cursor)?
+            != Vec::<u8>::try_from(output_checkpoint_data.reward_schedule().cursor)?
+        || input_checkpoint_data.reward_mode() != output_checkpoint_data.reward_mode()
+        || get_withdrawal_expiry(&input_checkpoint_data)?
+            != get_withdrawal_expiry(&output_checkpoint_data)?
     {
         return Err(Error::CheckpointDataMismatch);
     }
 
-    // check this is wether admin mode or checkpoint mode
+    // check this is wether admin mode, checkpoint mode or reconfigure mode
     let witness_args = load_witness_args(0, Source::GroupInput)?;
-    let is_admin_mode = {
+    let mode = {
         let input_type = witness_args.input_type().to_opt();
         if input_type.is_none() {
             return Err(Error::BadWitnessInputType);
         }
         match input_type.unwrap().raw_data().to_vec().first() {
-            Some(value) => value == &0,
-            None => return Err(Error::BadWitnessInputType),
+            Some(&mode::ADMIN) => MODE::ADMIN,
+            Some(&mode::CHECKPOINT) => MODE::CHECKPOINT,
+            Some(&mode::RECONFIGURE) => MODE::RECONFIGURE,
+            _ => return Err(Error::BadWitnessInputType),
         }
     };
 
     // get AT coins from AT cell
     let sudt_type_hash = input_checkpoint_data.sudt_type_hash();
-    let input_at_amount = get_sudt_by_type_hash(&sudt_type_hash, Source::Input)?;
-    let output_at_amount = get_sudt_by_type_hash(&sudt_type_hash, Source::Output)?;
+    let input_at_amount = sudt::get_total_amount(None, &sudt_type_hash, Source::Input)?;
+    let output_at_amount = sudt::get_total_amount(None, &sudt_type_hash, Source::Output)?;
 
     debug!(
         "input_at_amount = {}, output_at_amount = {}",
         input_at_amount, output_at_amount
     );
 
-    // admin mode
-    if is_admin_mode {
-        // check admin signature
-        if !secp256k1::verify_signature(&mut admin_identity.content()) {
-            return Err(Error::SignatureMismatch);
-        }
-        // check AT amount
-        if input_at_amount < output_at_amount {
-            return Err(Error::ATAmountMismatch);
-        }
-    // checkpoint mode
-    } else {
-        let checkpoint = witness_args.lock().to_opt();
-        if checkpoint.is_none() {
-            return Err(Error::WitnessLockError);
+    match mode {
+        MODE::ADMIN => {
+            debug!("admin mode");
+            // check admin signature
+            if !secp256k1::verify_signature(&mut admin_identity.content()) {
+                return Err(Error::SignatureMismatch);
+            }
+            // check AT amount
+            if input_at_amount < output_at_amount {
+                return Err(Error::ATAmountMismatch);
+            }
+            if input_checkpoint_data.minted_amount() != output_checkpoint_data.minted_amount() {
+                return Err(Error::MintedAmountMismatch);
+            }
+            if !is_params_unchanged(&input_checkpoint_data, &output_checkpoint_data)
+                || get_pending_params(&input_checkpoint_data)?
+                    != get_pending_params(&output_checkpoint_data)?
+            {
+                return Err(Error::CheckpointDataMismatch);
+            }
         }
-        if input_checkpoint_data.state() != output_checkpoint_data.state()
-            || input_checkpoint_data.unlock_period() != output_checkpoint_data.unlock_period()
-        {
-            return Err(Error::CheckpointDataMismatch);
+        MODE::RECONFIGURE => {
+            debug!("reconfigure mode");
+            // check admin signature
+            if !secp256k1::verify_signature(&mut admin_identity.content()) {
+                return Err(Error::SignatureMismatch);
+            }
+            // check nothing but pending params is changed
+            if input_at_amount != output_at_amount {
+                return Err(Error::ATAmountMismatch);
+            }
+            if input_checkpoint_data.state() != output_checkpoint_data.state()
+                || input_checkpoint_data.period() != output_checkpoint_data.period()
+                || input_checkpoint_data.era() != output_checkpoint_data.era()
+                || input_checkpoint_data.block_hash() != output_checkpoint_data.block_hash()
+                || input_checkpoint_data.minted_amount() != output_checkpoint_data.minted_amount()
+                || !is_params_unchanged(&input_checkpoint_data, &output_checkpoint_data)
+            {
+                return Err(Error::CheckpointDataMismatch);
+            }
+            // pending params can only take effect from a future era, or be cancelled, and the
+            // halving schedule can not be left without half_period once they are applied
+            if let Some(pending_params) = output_checkpoint_data.pending_params() {
+                let effective_era = bytes_to_u64(&pending_params.effective_era());
+                if effective_era <= bytes_to_u64(&input_checkpoint_data.era()) {
+                    return Err(Error::BadEffectiveEra);
+                }
+                if output_checkpoint_data.reward_schedule().version() == 0
+                    && bytes_to_u64(&pending_params.half_period()) == 0
+                {
+                    return Err(Error::BadPendingParams);
+                }
+            }
         }
+        MODE::CHECKPOINT => {
+            debug!("checkpoint mode");
+            let checkpoint = {
+                let witness_lock = witness_args.lock().to_opt();
+                if witness_lock.is_none() {
+                    return Err(Error::WitnessLockError);
+                }
+                Checkpoint::decode(&witness_lock.unwrap().raw_data())?
+            };
+            debug!(
+                "L2 checkpoint: block_number = {}, proposer = {:?}",
+                checkpoint.block_number(),
+                checkpoint.proposer()
+            );
+            if input_checkpoint_data.state() != output_checkpoint_data.state() {
+                return Err(Error::CheckpointDataMismatch);
+            }
+
+            // check state transition from input checkpoint to output checkpoint
+            if input_checkpoint_data.state() != 0x01 {
+                return Err(Error::BadCheckpointState);
+            }
+            let input_period = bytes_to_u64(&input_checkpoint_data.period());
+            let output_period = bytes_to_u64(&output_checkpoint_data.period());
+            if Some(output_period) != input_period.checked_add(1) {
+                return Err(Error::BadCheckpointPeriod);
+            }
+            let era_period = bytes_to_u32(&output_checkpoint_data.era_period());
+            if era_period == 0 {
+                return Err(Error::CheckpointDataError);
+            }
+            let output_era = bytes_to_u64(&output_checkpoint_data.era());
+            if output_era != output_period / era_period as u64 {
+                return Err(Error::BadCheckpointEra);
+            }
+            if &output_checkpoint_data.block_hash()[..] != checkpoint.block_hash() {
+                return Err(Error::BadCheckpointBlockHash);
+            }
+            let period_interval = bytes_to_u32(&output_checkpoint_data.period_interval());
+            if Some(checkpoint.block_number()) != output_period.checked_mul(period_interval as u64)
+                || checkpoint.proof_number() != checkpoint.block_number()
+            {
+                return Err(Error::BadCheckpointBlockNumber);
+            }
+            if &input_checkpoint_data.block_hash()[..] != checkpoint.last_checkpoint_block_hash() {
+                return Err(Error::BadLastCheckpointBlockHash);
+            }
 
-        // 加载 witness 中的 checkpoint（不能为空），解析 checkpoint（rlp 编码），获得 L2_block_hash, L2_block_number, L2_signature,
-        // L2_bitmap（参与聚合签名的共识节点编号）, L2_last_checkpoint_block_hash, L2_proposer 等字段。根据 L2 的 block_hash 计算规则
-        // 计算 block_hash（Kaccak 哈希算法），验证是否等于 L2_block_hash。
+            // apply pending params once output.era reaches their effective era
+            match input_checkpoint_data.pending_params() {
+                Some(pending_params)
+                    if bytes_to_u64(&pending_params.effective_era()) <= output_era =>
+                {
+                    if output_checkpoint_data.pending_params().is_some()
+                        || output_checkpoint_data.unlock_period() != pending_params.unlock_period()
+                        || output_checkpoint_data.base_reward() != pending_params.base_reward()
+                        || output_checkpoint_data.half_period() != pending_params.half_period()
+                    {
+                        return Err(Error::PendingParamsMismatch);
+                    }
+                }
+                _ => {
+                    if !is_params_unchanged(&input_checkpoint_data, &output_checkpoint_data)
+                        || get_pending_params(&input_checkpoint_data)?
+                            != get_pending_params(&output_checkpoint_data)?
+                    {
+                        return Err(Error::CheckpointDataMismatch);
+                    }
+                }
+            }
 
-        // 根据 stake_type_hash 在 cell_deps 里查找 Stake Cell，根据规则计算出 output.era 的共识节点列表，验证 L2_bitmap 中参与共识的
-        // 节点数量超过 2/3 的共识节点。根据 L2_bitmap 获得参与聚合签名的共识节点的 bls_puk_key，使用 BLS 聚合签名算法验签。
+            // check L2 block_hash, which is the keccak hash of the RLP encoded block header
+            if &keccak::keccak256(checkpoint.header()) != checkpoint.block_hash() {
+                return Err(Error::BlockHashMismatch);
+            }
 
-        // 验证 input.state == 0x01 && output.period == input.period + 1 && output.era == ⌊output.period/era_period⌋ && output.block_hash
-        // == L2_block_hash && output.period * period_interval == L2_block_number && input.block_hash == L2_last_checkpoint_block_hash
+            // check validators marked in L2_bitmap are more than 2/3 of validators in output.era
+            let stake_data =
+                get_stake_data_from_celldeps(&input_checkpoint_data.stake_type_hash())?;
+            let validators = protocol::stake::get_validators_by_era(&stake_data, output_era)?;
+            let signers = get_signers_by_bitmap(&validators, checkpoint.bitmap())?;
+            if signers.len() * 3 <= validators.len() * 2 {
+                return Err(Error::QuorumNotReached);
+            }
 
-        // check AT amount
-        let base_reward = bytes_to_u128(&input_checkpoint_data.base_reward());
-        let period = bytes_to_u64(&input_checkpoint_data.period());
-        let half_period = bytes_to_u64(&input_checkpoint_data.half_period());
-        if half_period == 0 {
-            return Err(Error::CheckpointDataError);
-        }
-        if output_at_amount - input_at_amount
-            != base_reward / 2u128.pow((period / half_period) as u32)
-        {
-            return Err(Error::ATAmountMismatch);
-        }
+            // check aggregated signature of L2 block_hash from validators marked in L2_bitmap
+            let bls_pub_keys = signers
+                .iter()
+                .map(|signer| signer.bls_pub_key())
+                .collect::<Vec<_>>();
+            if !bls::verify_aggregate_signature(
+                &bls_pub_keys,
+                checkpoint.block_hash(),
+                checkpoint.signature(),
+            ) {
+                return Err(Error::BlsSignatureMismatch);
+            }
 
-        // construct Withdrawal lock
+            // check AT amount and the cumulative minted amount
+            let reward = get_period_reward(&input_checkpoint_data, input_period)?;
+            let minted_at_amount = output_at_amount
+                .checked_sub(input_at_amount)
+                .ok_or(Error::ATAmountOverflow)?;
+            if minted_at_amount != reward {
+                return Err(Error::ATAmountMismatch);
+            }
+            let minted_amount = bytes_to_u128(&input_checkpoint_data.minted_amount())
+                .checked_add(reward)
+                .ok_or(Error::ATAmountOverflow)?;
+            if bytes_to_u128(&output_checkpoint_data.minted_amount()) != minted_amount {
+                return Err(Error::MintedAmountMismatch);
+            }
 
-        // 根据 L2_proposer 在共识节点列表中查找对应的 Identity，再结合 admin_identity, stake_lock_hash 和 withdrawal_lock_code_hash
-        // 和 withdrawal_lock_hash_type 构造出 withdrawal lock，然后计算出 withdrawal_lock_hash
+            // the reward is split by reward_mode among validators, and each validator shares its
+            // reward with its delegators
+            let reward_validators = match output_checkpoint_data.reward_mode() {
+                0 => {
+                    // L2_proposer takes the whole reward, whose node identity is found in
+                    // validators
+                    let proposer = validators
+                        .iter()
+                        .find(|validator| validator.l2_address()[..] == checkpoint.proposer()[..])
+                        .ok_or(Error::ProposerNotFound)?;
+                    vec![(proposer, reward)]
+                }
+                1 => get_signer_rewards(&stake_data, &signers, reward, output_era)?,
+                _ => return Err(Error::CheckpointDataError),
+            };
+            let mut rewards: BTreeMap<Vec<u8>, u128> = BTreeMap::new();
+            for (validator, validator_reward) in reward_validators {
+                let (validator_reward, delegator_rewards) = protocol::stake::split_reward(
+                    &stake_data,
+                    validator,
+                    validator_reward,
+                    output_era,
+                )?;
+                *rewards
+                    .entry(Vec::<u8>::try_from(validator.identity().cursor)?)
+                    .or_insert(0) += validator_reward;
+                for (delegator_identity, delegator_reward) in delegator_rewards {
+                    *rewards.entry(delegator_identity).or_insert(0) += delegator_reward;
+                }
+            }
 
-        // 根据 withdrawal_lock_hash 和 sudt_type_hash 查找 input 和 output 的 Withdrawal AT cell，验证 output 总额 - input 总额 ==
-        // base_reward / (2^⌊period/half_period⌋)，且 output.{each Withdrawal AT cell}.period == output.{Checkpoint Cell}.period +
-        // output.{Checkpoint Cell}.unlock_period
+            // check every rewarded identity receives its reward in its Withdrawal AT cell
+            let admin_identity = Vec::<u8>::try_from(admin_identity.cursor)?;
+            let withdrawal_period = output_period
+                .checked_add(bytes_to_u32(&output_checkpoint_data.unlock_period()) as u64)
+                .ok_or(Error::CheckpointDataError)?;
+            for (identity, reward) in rewards {
+                if reward > 0 {
+                    check_withdrawal_reward(
+                        &input_checkpoint_data,
+                        &admin_identity,
+                        &type_id_hash,
+                        &identity,
+                        reward,
+                        withdrawal_period,
+                    )?;
+                }
+            }
+        }
     }
 
     Ok(())
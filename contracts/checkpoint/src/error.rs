@@ -1,6 +1,8 @@
 use ckb_std::error::SysError;
+use protocol::{rlp, stake, sudt, MoleculeError};
 
 /// Error
+#[allow(clippy::enum_variant_names)]
 #[repr(i8)]
 pub enum Error {
     IndexOutOfBound = 1,
@@ -17,6 +19,35 @@ pub enum Error {
     WitnessLockError,
     ATAmountMismatch,
     SignatureMismatch,
+    CheckpointRlpTruncated,
+    CheckpointRlpNonCanonical,
+    CheckpointRlpExpectedList,
+    CheckpointRlpExpectedBytes,
+    CheckpointRlpItemCount,
+    CheckpointRlpItemLength,
+    CheckpointRlpTrailingBytes,
+    BlockHashMismatch,
+    StakeDataError,
+    StakeDataEmpty,
+    BitmapOutOfRange,
+    BlsSignatureMismatch,
+    QuorumNotReached,
+    BadCheckpointState,
+    BadCheckpointPeriod,
+    BadCheckpointEra,
+    BadCheckpointBlockHash,
+    BadCheckpointBlockNumber,
+    BadLastCheckpointBlockHash,
+    ProposerNotFound,
+    BadWithdrawalData,
+    BadWithdrawalPeriod,
+    WithdrawalAmountMismatch,
+    ATAmountOverflow,
+    BadRewardSchedule,
+    MintedAmountMismatch,
+    BadEffectiveEra,
+    PendingParamsMismatch,
+    BadPendingParams,
 }
 
 impl From<SysError> for Error {
@@ -31,3 +62,46 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl From<rlp::Error> for Error {
+    fn from(err: rlp::Error) -> Self {
+        use rlp::Error::*;
+        match err {
+            Truncated => Self::CheckpointRlpTruncated,
+            NonCanonical => Self::CheckpointRlpNonCanonical,
+            ExpectedList => Self::CheckpointRlpExpectedList,
+            ExpectedBytes => Self::CheckpointRlpExpectedBytes,
+            ItemCount => Self::CheckpointRlpItemCount,
+            ItemLength => Self::CheckpointRlpItemLength,
+            TrailingBytes => Self::CheckpointRlpTrailingBytes,
+        }
+    }
+}
+
+impl From<MoleculeError> for Error {
+    fn from(_: MoleculeError) -> Self {
+        Self::Encoding
+    }
+}
+
+impl From<sudt::Error> for Error {
+    fn from(err: sudt::Error) -> Self {
+        use sudt::Error::*;
+        match err {
+            Sys(err) => Self::from(err),
+            BadSudtDataFormat => Self::BadSudtDataFormat,
+            BadWithdrawalData => Self::BadWithdrawalData,
+            AmountOverflow => Self::ATAmountOverflow,
+        }
+    }
+}
+
+impl From<stake::Error> for Error {
+    fn from(err: stake::Error) -> Self {
+        use stake::Error::*;
+        match err {
+            Molecule(err) => Self::from(err),
+            WeightOverflow => Self::ATAmountOverflow,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+#![no_std]
+
+// BLS12-381 signatures with public keys in G1 and signatures in G2, following the
+// proof-of-possession scheme of the IETF BLS signature draft
+//
+// Aggregating public keys of one message is only safe against rogue keys when each of them has
+// been registered with a valid proof of possession, see `verify_proof_of_possession`
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    multi_miller_loop, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective, Gt,
+};
+
+const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+// public key is the uncompressed G1 point prefixed with 0x04
+const PUBLIC_KEY_SIZE: usize = 97;
+const PUBLIC_KEY_PREFIX: u8 = 0x04;
+
+// signature is the compressed G2 point
+const SIGNATURE_SIZE: usize = 96;
+
+// `from_uncompressed` rejects points which are not on the curve or not in the prime order
+// subgroup, and the identity is rejected here since it is a valid key of nobody
+fn parse_public_key(pub_key: &[u8]) -> Option<G1Affine> {
+    if pub_key.len() != PUBLIC_KEY_SIZE || pub_key[0] != PUBLIC_KEY_PREFIX {
+        return None;
+    }
+    let mut bytes = [0u8; PUBLIC_KEY_SIZE - 1];
+    bytes.copy_from_slice(&pub_key[1..]);
+    let point: Option<G1Affine> = G1Affine::from_uncompressed(&bytes).into();
+    point.filter(|point| !bool::from(point.is_identity()))
+}
+
+fn parse_signature(signature: &[u8]) -> Option<G2Affine> {
+    if signature.len() != SIGNATURE_SIZE {
+        return None;
+    }
+    let mut bytes = [0u8; SIGNATURE_SIZE];
+    bytes.copy_from_slice(signature);
+    G2Affine::from_compressed(&bytes).into()
+}
+
+// e(pub_key, H(message)) == e(g1, signature)
+fn core_verify(pub_key: &G1Affine, message: &[u8], signature: &G2Affine, dst: &[u8]) -> bool {
+    let hashed_message =
+        <G2Projective as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::hash_to_curve(message, dst);
+    let result = multi_miller_loop(&[
+        (pub_key, &G2Prepared::from(G2Affine::from(hashed_message))),
+        (&-G1Affine::generator(), &G2Prepared::from(*signature)),
+    ])
+    .final_exponentiation();
+    result == Gt::identity()
+}
+
+/// check `pub_key` is a well-formed public key, which is a non-identity point of the prime order
+/// subgroup of G1
+pub fn is_valid_public_key(pub_key: &[u8]) -> bool {
+    parse_public_key(pub_key).is_some()
+}
+
+/// verify `proof` is the proof of possession of `pub_key`, which is the signature of the
+/// compressed public key signed by its secret key
+pub fn verify_proof_of_possession(pub_key: &[u8], proof: &[u8]) -> bool {
+    let pub_key = match parse_public_key(pub_key) {
+        Some(point) => point,
+        None => return false,
+    };
+    let proof = match parse_signature(proof) {
+        Some(point) => point,
+        None => return false,
+    };
+    core_verify(&pub_key, &pub_key.to_compressed(), &proof, POP_DST)
+}
+
+/// verify an aggregated signature of `message` signed by all owners of `pub_keys`, each of which
+/// must have been checked by `verify_proof_of_possession` when it was registered
+pub fn verify_aggregate_signature(pub_keys: &[Vec<u8>], message: &[u8], signature: &[u8]) -> bool {
+    if pub_keys.is_empty() {
+        return false;
+    }
+    let mut aggregated_pub_key = G1Projective::identity();
+    for pub_key in pub_keys {
+        match parse_public_key(pub_key) {
+            Some(point) => aggregated_pub_key += point,
+            None => return false,
+        }
+    }
+    let signature = match parse_signature(signature) {
+        Some(point) => point,
+        None => return false,
+    };
+    core_verify(
+        &G1Affine::from(aggregated_pub_key),
+        message,
+        &signature,
+        DST,
+    )
+}
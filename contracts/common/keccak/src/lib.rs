@@ -0,0 +1,101 @@
+#![no_std]
+
+// Keccak-256 as used by Ethereum and Axon, which pads with the original Keccak
+// domain byte `0x01` rather than the SHA3 one
+
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+// rotation offsets of lane (x, y), indexed by `x + 5 * y`
+const ROTATIONS: [u32; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+fn keccak_f(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS.iter() {
+        // theta
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+        // rho and pi
+        let mut b = [0u64; 25];
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] =
+                    state[x + 5 * y].rotate_left(ROTATIONS[x + 5 * y]);
+            }
+        }
+        // chi
+        for x in 0..5 {
+            for y in 0..5 {
+                state[x + 5 * y] =
+                    b[x + 5 * y] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+            }
+        }
+        // iota
+        state[0] ^= round_constant;
+    }
+}
+
+fn absorb_block(state: &mut [u64; 25], block: &[u8]) {
+    for (i, lane) in block.chunks(8).enumerate() {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(lane);
+        state[i] ^= u64::from_le_bytes(bytes);
+    }
+    keccak_f(state);
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+    let mut blocks = data.chunks_exact(RATE);
+    for block in &mut blocks {
+        absorb_block(&mut state, block);
+    }
+    // pad the last block
+    let remainder = blocks.remainder();
+    let mut last_block = [0u8; RATE];
+    last_block[..remainder.len()].copy_from_slice(remainder);
+    last_block[remainder.len()] ^= 0x01;
+    last_block[RATE - 1] ^= 0x80;
+    absorb_block(&mut state, &last_block);
+    // squeeze
+    let mut hash = [0u8; 32];
+    for (i, lane) in state[..4].iter().enumerate() {
+        hash[i * 8..(i + 1) * 8].copy_from_slice(&lane.to_le_bytes());
+    }
+    hash
+}
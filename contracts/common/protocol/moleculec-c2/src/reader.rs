@@ -5,6 +5,7 @@ use alloc::rc::Rc;
 use alloc::vec::Vec;
 use core::cell::RefCell;
 use core::cmp::min;
+use core::convert::{Infallible, TryFrom};
 
 #[derive(Debug)]
 pub enum Error {
@@ -20,6 +21,13 @@ pub enum Error {
     Read,
 }
 
+// conversions into wrapper types never fail, so that they can share `?` with fallible ones
+impl From<Infallible> for Error {
+    fn from(error: Infallible) -> Self {
+        match error {}
+    }
+}
+
 pub trait Read {
     /**
      * try to read `buf.len()` bytes from data source with `offset`, then fill it in `buf`.
@@ -57,34 +65,40 @@ pub struct Union {
 
 pub fn read_at(cur: &Cursor, buf: &mut [u8]) -> Result<usize, Error> {
     let read_len = min(cur.size, buf.len() as usize);
-    let mut ds = &mut *cur.data_source.borrow_mut();
+    if read_len == 0 {
+        return Ok(0);
+    }
+    let ds = &mut *cur.data_source.borrow_mut();
     if read_len > ds.max_cache_size {
-        return ds.reader.read(buf, cur.offset);
+        return ds.reader.read(&mut buf[..read_len], cur.offset);
     }
-    if cur.offset < ds.start_point || (cur.offset + read_len) > (ds.start_point + ds.cache_size) {
+    let read_end = cur.offset.checked_add(read_len).ok_or(Error::Overflow)?;
+    if cur.offset < ds.start_point || read_end > (ds.start_point + ds.cache_size) {
         let reader = &ds.reader;
-        let size = reader.read(&mut ds.cache[..], cur.offset).unwrap();
-        if size < read_len {
-            panic!("read_at `if size < read_len`");
+        let size = reader.read(&mut ds.cache[..], cur.offset)?;
+        if size < read_len || size > ds.max_cache_size {
+            return Err(Error::Read);
         }
         ds.cache_size = size;
         ds.start_point = cur.offset;
-
-        if ds.cache_size > ds.max_cache_size {
-            panic!("read_at `if ds.cache_size > ds.max_cache_size`");
-        }
-    }
-    if cur.offset < ds.start_point || (cur.offset - ds.start_point) > ds.max_cache_size {
-        panic!("read_at `if cur.offset < ds.start_point || ...`");
     }
     let read_point = cur.offset - ds.start_point;
     if read_point + read_len > ds.cache_size {
-        panic!("read_at `if read_point + read_len > ds.cache_size`")
+        return Err(Error::Read);
     }
-    buf.copy_from_slice(&ds.cache[read_point as usize..(read_point + read_len) as usize]);
+    buf[..read_len].copy_from_slice(&ds.cache[read_point..read_point + read_len]);
     Ok(read_len)
 }
 
+// fill the whole `buf`, or fail if the cursor is shorter than `buf`
+fn read_exact(cur: &Cursor, buf: &mut [u8]) -> Result<(), Error> {
+    let size = read_at(cur, buf)?;
+    if size != buf.len() {
+        return Err(Error::TotalSize);
+    }
+    Ok(())
+}
+
 impl Cursor {
     /**
     cache_size: normally it can be set to MAX_CACHE_SIZE(2K)
@@ -111,33 +125,29 @@ impl Cursor {
         }
     }
 
-    pub fn add_offset(&mut self, offset: usize) {
-        self.offset = self.offset.checked_add(offset).unwrap();
+    pub fn add_offset(&mut self, offset: usize) -> Result<(), Error> {
+        self.offset = self.offset.checked_add(offset).ok_or(Error::Overflow)?;
+        Ok(())
     }
 
-    pub fn sub_size(&mut self, shrink_size: usize) {
-        self.size = self.size.checked_sub(shrink_size).unwrap();
+    pub fn sub_size(&mut self, shrink_size: usize) -> Result<(), Error> {
+        self.size = self.size.checked_sub(shrink_size).ok_or(Error::Overflow)?;
+        Ok(())
     }
 
-    pub fn validate(&self) {
-        if let Some(size) = self.offset.checked_add(self.size) {
-            if size > self.data_source.borrow().total_size {
-                panic!("validate: size > cur.source.total_size")
-            }
+    pub fn validate(&self) -> Result<(), Error> {
+        let size = self.offset.checked_add(self.size).ok_or(Error::Overflow)?;
+        if size > self.data_source.borrow().total_size {
+            Err(Error::OutOfBound)
         } else {
-            panic!("validate")
+            Ok(())
         }
     }
 
-    pub fn unpack_number(&self) -> usize {
+    pub fn unpack_number(&self) -> Result<usize, Error> {
         let mut src = [0u8; 4];
-        let size = read_at(self, &mut src[..]).unwrap();
-        if size != 4 {
-            panic!("unpack_number");
-        } else {
-            let res = u32::from_le_bytes(src);
-            res as usize
-        }
+        read_exact(self, &mut src[..])?;
+        Ok(u32::from_le_bytes(src) as usize)
     }
 
     pub fn verify_fixed_size(&self, total_size: usize) -> Result<(), Error> {
@@ -150,9 +160,9 @@ impl Cursor {
 
     pub fn fixvec_verify(&self, item_size: usize) -> Result<(), Error> {
         if self.size < NUM_T_SIZE {
-            panic!("fixvec_verify")
+            return Err(Error::Header);
         }
-        let item_count = self.unpack_number();
+        let item_count = self.unpack_number()?;
         if item_count == 0 {
             if self.size == NUM_T_SIZE {
                 return Ok(());
@@ -161,7 +171,7 @@ impl Cursor {
             }
         }
 
-        let total_size = calculate_offset(item_size, item_count, NUM_T_SIZE);
+        let total_size = calculate_offset(item_size, item_count, NUM_T_SIZE)?;
         if self.size == total_size {
             Ok(())
         } else {
@@ -169,48 +179,102 @@ impl Cursor {
         }
     }
 
+    /**
+     * verify the header of a dynvec: total size, and offsets of items which must start right
+     * after the header, be increasing and stay inside the dynvec
+     */
+    pub fn dynvec_verify(&self) -> Result<(), Error> {
+        if self.size < NUM_T_SIZE {
+            return Err(Error::Header);
+        }
+        self.validate()?;
+        if self.unpack_number()? != self.size {
+            return Err(Error::TotalSize);
+        }
+        if self.size == NUM_T_SIZE {
+            return Ok(());
+        }
+        if self.size < NUM_T_SIZE * 2 {
+            return Err(Error::Header);
+        }
+        let mut cur2 = self.clone();
+        cur2.add_offset(NUM_T_SIZE)?;
+        let first_offset = cur2.unpack_number()?;
+        if first_offset % NUM_T_SIZE != 0 || first_offset < NUM_T_SIZE * 2 {
+            return Err(Error::Offset);
+        }
+        if first_offset > self.size {
+            return Err(Error::Header);
+        }
+        let item_count = first_offset / NUM_T_SIZE - 1;
+        let mut last_offset = first_offset;
+        for i in 1..item_count {
+            cur2.offset = self.offset;
+            cur2.add_offset(calculate_offset(NUM_T_SIZE, i + 1, 0)?)?;
+            let offset = cur2.unpack_number()?;
+            if offset < last_offset || offset > self.size {
+                return Err(Error::Offset);
+            }
+            last_offset = offset;
+        }
+        Ok(())
+    }
+
+    /**
+     * verify the header of a table with `field_count` fields, extra fields are rejected unless
+     * `compatible` is set
+     */
+    pub fn table_verify(&self, field_count: usize, compatible: bool) -> Result<(), Error> {
+        self.dynvec_verify()?;
+        let count = self.table_actual_field_count()?;
+        if count < field_count || (!compatible && count > field_count) {
+            return Err(Error::FieldCount);
+        }
+        Ok(())
+    }
+
     pub fn option_is_none(&self) -> bool {
         self.size == 0
     }
-    pub fn fixvec_length(&self) -> usize {
+    pub fn fixvec_length(&self) -> Result<usize, Error> {
         self.unpack_number()
     }
 
-    pub fn dynvec_length(&self) -> usize {
+    pub fn dynvec_length(&self) -> Result<usize, Error> {
         if self.size == NUM_T_SIZE {
-            0
+            Ok(0)
         } else {
             let mut cur2 = self.clone();
-            cur2.add_offset(NUM_T_SIZE);
-            cur2.sub_size(NUM_T_SIZE);
-            cur2.validate();
+            cur2.add_offset(NUM_T_SIZE)?;
+            cur2.sub_size(NUM_T_SIZE)?;
+            cur2.validate()?;
             cur2.get_item_count()
         }
     }
 
-    pub fn get_item_count(&self) -> usize {
-        let count = self.unpack_number() / 4;
+    pub fn get_item_count(&self) -> Result<usize, Error> {
+        let count = self.unpack_number()? / 4;
         if count == 0 {
-            panic!("get_item_count");
+            Err(Error::Offset)
         } else {
-            count - 1
+            Ok(count - 1)
         }
     }
 
-    pub fn table_actual_field_count(&self) -> usize {
+    pub fn table_actual_field_count(&self) -> Result<usize, Error> {
         self.dynvec_length()
     }
 
-    pub fn table_has_extra_fields(&self, field_count: usize) -> bool {
-        let count = self.table_actual_field_count();
-        count > field_count
+    pub fn table_has_extra_fields(&self, field_count: usize) -> Result<bool, Error> {
+        let count = self.table_actual_field_count()?;
+        Ok(count > field_count)
     }
 
     pub fn slice_by_offset(&self, offset: usize, size: usize) -> Result<Cursor, Error> {
         let mut cur2 = self.clone();
-        cur2.add_offset(offset);
+        cur2.add_offset(offset)?;
         cur2.size = size;
-        cur2.validate();
+        cur2.validate()?;
         Ok(cur2)
     }
 
@@ -220,14 +284,14 @@ impl Cursor {
         item_index: usize,
     ) -> Result<Cursor, Error> {
         let mut cur2 = self.clone();
-        let item_count = self.unpack_number();
+        let item_count = self.unpack_number()?;
         if item_index >= item_count {
             Err(Error::OutOfBound)
         } else {
-            let offset = calculate_offset(item_size, item_index, NUM_T_SIZE);
-            cur2.add_offset(offset);
+            let offset = calculate_offset(item_size, item_index, NUM_T_SIZE)?;
+            cur2.add_offset(offset)?;
             cur2.size = item_size;
-            cur2.validate();
+            cur2.validate()?;
             Ok(cur2)
         }
     }
@@ -235,33 +299,32 @@ impl Cursor {
     pub fn dynvec_slice_by_index(&self, item_index: usize) -> Result<Cursor, Error> {
         let mut res = self.clone();
         let mut temp = self.clone();
-        let total_size = self.unpack_number();
-        temp.add_offset(NUM_T_SIZE);
-        let item_count = temp.get_item_count();
+        let total_size = self.unpack_number()?;
+        temp.add_offset(NUM_T_SIZE)?;
+        let item_count = temp.get_item_count()?;
         if item_index >= item_count {
             return Err(Error::OutOfBound);
         }
         temp.offset = self.offset;
-        let temp_offset = calculate_offset(NUM_T_SIZE, item_index + 1, 0);
-        temp.add_offset(temp_offset);
-        let item_start = temp.unpack_number();
+        let temp_offset = calculate_offset(NUM_T_SIZE, item_index + 1, 0)?;
+        temp.add_offset(temp_offset)?;
+        let item_start = temp.unpack_number()?;
         if (item_index + 1) == item_count {
             res.offset = self.offset;
-            res.add_offset(item_start);
+            res.add_offset(item_start)?;
             res.size = total_size;
-            res.sub_size(item_start)
         } else {
             temp.offset = self.offset;
-            let calc_offset = calculate_offset(NUM_T_SIZE, item_index + 2, 0);
-            temp.add_offset(calc_offset);
+            let calc_offset = calculate_offset(NUM_T_SIZE, item_index + 2, 0)?;
+            temp.add_offset(calc_offset)?;
 
-            let item_end = temp.unpack_number();
+            let item_end = temp.unpack_number()?;
             res.offset = self.offset;
-            res.add_offset(item_start);
+            res.add_offset(item_start)?;
             res.size = item_end;
-            res.sub_size(item_start);
         }
-        res.validate();
+        res.sub_size(item_start)?;
+        res.validate()?;
         Ok(res)
     }
 
@@ -271,9 +334,9 @@ impl Cursor {
 
     pub fn fixvec_slice_raw_bytes(&self) -> Result<Cursor, Error> {
         let mut res = self.clone();
-        res.add_offset(NUM_T_SIZE);
-        res.size = self.unpack_number();
-        res.validate();
+        res.add_offset(NUM_T_SIZE)?;
+        res.size = self.unpack_number()?;
+        res.validate()?;
         Ok(res)
     }
 
@@ -285,119 +348,133 @@ impl Cursor {
         self.fixvec_slice_raw_bytes()
     }
 
-    pub fn union_unpack(&self) -> Union {
-        let item_id = self.unpack_number();
+    pub fn union_unpack(&self) -> Result<Union, Error> {
+        let item_id = self.unpack_number()?;
         let mut cursor = self.clone();
-        cursor.add_offset(NUM_T_SIZE);
-        cursor.sub_size(NUM_T_SIZE);
-        cursor.validate();
-        Union { item_id, cursor }
+        cursor.add_offset(NUM_T_SIZE)?;
+        cursor.sub_size(NUM_T_SIZE)?;
+        cursor.validate()?;
+        Ok(Union { item_id, cursor })
     }
 }
 
-fn calculate_offset(item_size: usize, item_count: usize, offset: usize) -> usize {
-    let res = item_size.checked_mul(item_count).unwrap();
-    res.checked_add(offset).unwrap()
+fn calculate_offset(item_size: usize, item_count: usize, offset: usize) -> Result<usize, Error> {
+    let res = item_size.checked_mul(item_count).ok_or(Error::Overflow)?;
+    res.checked_add(offset).ok_or(Error::Overflow)
 }
 
-impl From<Cursor> for u64 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for u64 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 8];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_u64");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        u64::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(u64::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for i64 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for i64 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 8];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_i64");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        i64::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(i64::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for u32 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for u32 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 4];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_u32");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        u32::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(u32::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for i32 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for i32 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 4];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_i32");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        i32::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(i32::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for u16 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for u16 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 2];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_u16");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        u16::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(u16::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for i16 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for i16 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 2];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_i16");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        i16::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(i16::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for u8 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for u8 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 1];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_u8");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        u8::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(u8::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for i8 {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for i8 {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = [0u8; 1];
-        let size = read_at(&cur, &mut buf[..]).unwrap();
-        if size != buf.len() {
-            panic!("convert_to_i8");
+        if cur.size != buf.len() {
+            return Err(Error::TotalSize);
         }
-        i8::from_le_bytes(buf)
+        read_exact(&cur, &mut buf[..])?;
+        Ok(i8::from_le_bytes(buf))
     }
 }
 
-impl From<Cursor> for Vec<u8> {
-    fn from(cur: Cursor) -> Self {
+impl TryFrom<Cursor> for Vec<u8> {
+    type Error = Error;
+
+    fn try_from(cur: Cursor) -> Result<Self, Error> {
         let mut buf = Vec::<u8>::new();
         buf.resize(cur.size, 0);
-
-        let size = read_at(&cur, buf.as_mut_slice()).unwrap();
-        if size != buf.len() {
-            panic!("convert to Vec<u8>");
-        }
-        buf
+        read_exact(&cur, buf.as_mut_slice())?;
+        Ok(buf)
     }
 }
 
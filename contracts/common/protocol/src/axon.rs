@@ -2,7 +2,11 @@
 #![allow(unused_imports)]
 extern crate alloc;
 use alloc::vec::Vec;
-use molecule2::Cursor;
+use core::convert::TryInto;
+use molecule2::{Cursor, Error};
+
+// accessors without `try_` prefix panic on malformed data, so `verify` the whole structure
+// before using them
 
 pub struct Byte4 {
     pub cursor: Cursor,
@@ -14,6 +18,12 @@ impl From<Cursor> for Byte4 {
     }
 }
 
+impl Byte4 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(4)
+    }
+}
+
 impl Byte4 {
     pub fn len(&self) -> usize {
         4
@@ -22,8 +32,12 @@ impl Byte4 {
 
 impl Byte4 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -37,6 +51,12 @@ impl From<Cursor> for Byte8 {
     }
 }
 
+impl Byte8 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(8)
+    }
+}
+
 impl Byte8 {
     pub fn len(&self) -> usize {
         8
@@ -45,8 +65,12 @@ impl Byte8 {
 
 impl Byte8 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -60,6 +84,12 @@ impl From<Cursor> for Byte10 {
     }
 }
 
+impl Byte10 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(10)
+    }
+}
+
 impl Byte10 {
     pub fn len(&self) -> usize {
         10
@@ -68,8 +98,12 @@ impl Byte10 {
 
 impl Byte10 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -83,6 +117,12 @@ impl From<Cursor> for Byte16 {
     }
 }
 
+impl Byte16 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(16)
+    }
+}
+
 impl Byte16 {
     pub fn len(&self) -> usize {
         16
@@ -91,8 +131,12 @@ impl Byte16 {
 
 impl Byte16 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -106,6 +150,12 @@ impl From<Cursor> for Byte20 {
     }
 }
 
+impl Byte20 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(20)
+    }
+}
+
 impl Byte20 {
     pub fn len(&self) -> usize {
         20
@@ -114,8 +164,12 @@ impl Byte20 {
 
 impl Byte20 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -129,6 +183,12 @@ impl From<Cursor> for Byte32 {
     }
 }
 
+impl Byte32 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(32)
+    }
+}
+
 impl Byte32 {
     pub fn len(&self) -> usize {
         32
@@ -137,8 +197,12 @@ impl Byte32 {
 
 impl Byte32 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -152,6 +216,12 @@ impl From<Cursor> for Byte97 {
     }
 }
 
+impl Byte97 {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(97)
+    }
+}
+
 impl Byte97 {
     pub fn len(&self) -> usize {
         97
@@ -160,8 +230,12 @@ impl Byte97 {
 
 impl Byte97 {
     pub fn get(&self, index: usize) -> u8 {
-        let cur = self.cursor.slice_by_offset(1 * index, 1).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(1 * index, 1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -175,17 +249,34 @@ impl From<Cursor> for Identity {
     }
 }
 
+impl Identity {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(2, false)?;
+        self.cursor.table_slice_by_index(0)?.verify_fixed_size(1)?;
+        Byte20::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Ok(())
+    }
+}
+
 impl Identity {
     pub fn flag(&self) -> u8 {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_flag().unwrap()
+    }
+
+    pub fn try_flag(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl Identity {
     pub fn content(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_content().unwrap()
+    }
+
+    pub fn try_content(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -199,38 +290,82 @@ impl From<Cursor> for StakeInfo {
     }
 }
 
+impl StakeInfo {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(6, false)?;
+        Identity::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte20::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Byte97::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Byte8::from(self.cursor.table_slice_by_index(4)?).verify()?;
+        self.cursor.table_slice_by_index(5)?.verify_fixed_size(1)?;
+        Ok(())
+    }
+}
+
 impl StakeInfo {
     pub fn identity(&self) -> Identity {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_identity().unwrap()
+    }
+
+    pub fn try_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeInfo {
     pub fn l2_address(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_l2_address().unwrap()
+    }
+
+    pub fn try_l2_address(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeInfo {
     pub fn bls_pub_key(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(2).unwrap();
-        cur.into()
+        self.try_bls_pub_key().unwrap()
+    }
+
+    pub fn try_bls_pub_key(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeInfo {
     pub fn stake_amount(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(3).unwrap();
-        cur.into()
+        self.try_stake_amount().unwrap()
+    }
+
+    pub fn try_stake_amount(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeInfo {
     pub fn inauguration_era(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(4).unwrap();
-        cur.into()
+        self.try_inauguration_era().unwrap()
+    }
+
+    pub fn try_inauguration_era(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(4)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl StakeInfo {
+    pub fn commission_rate(&self) -> u8 {
+        self.try_commission_rate().unwrap()
+    }
+
+    pub fn try_commission_rate(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(5)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -244,16 +379,34 @@ impl From<Cursor> for StakeInfoVec {
     }
 }
 
+impl StakeInfoVec {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.dynvec_verify()?;
+        for i in 0..self.try_len()? {
+            self.try_get(i)?.verify()?;
+        }
+        Ok(())
+    }
+}
+
 impl StakeInfoVec {
     pub fn len(&self) -> usize {
+        self.try_len().unwrap()
+    }
+
+    pub fn try_len(&self) -> Result<usize, Error> {
         self.cursor.dynvec_length()
     }
 }
 
 impl StakeInfoVec {
     pub fn get(&self, index: usize) -> StakeInfo {
-        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
-        cur.into()
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<StakeInfo, Error> {
+        let cur = self.cursor.dynvec_slice_by_index(index)?;
+        Ok(cur.try_into()?)
     }
 }
 // warning: IdentityOpt not implemented for Rust
@@ -266,6 +419,254 @@ impl From<Cursor> for IdentityOpt {
     }
 }
 
+impl IdentityOpt {
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.cursor.option_is_none() {
+            Ok(())
+        } else {
+            Identity::from(self.cursor.clone()).verify()
+        }
+    }
+}
+
+pub struct DelegateInfo {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for DelegateInfo {
+    fn from(cursor: Cursor) -> Self {
+        DelegateInfo { cursor }
+    }
+}
+
+impl DelegateInfo {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(4, false)?;
+        Identity::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Identity::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte8::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Ok(())
+    }
+}
+
+impl DelegateInfo {
+    pub fn delegator_identity(&self) -> Identity {
+        self.try_delegator_identity().unwrap()
+    }
+
+    pub fn try_delegator_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl DelegateInfo {
+    pub fn validator_identity(&self) -> Identity {
+        self.try_validator_identity().unwrap()
+    }
+
+    pub fn try_validator_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl DelegateInfo {
+    pub fn amount(&self) -> Vec<u8> {
+        self.try_amount().unwrap()
+    }
+
+    pub fn try_amount(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl DelegateInfo {
+    pub fn inauguration_era(&self) -> Vec<u8> {
+        self.try_inauguration_era().unwrap()
+    }
+
+    pub fn try_inauguration_era(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct DelegateInfoVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for DelegateInfoVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl DelegateInfoVec {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.dynvec_verify()?;
+        for i in 0..self.try_len()? {
+            self.try_get(i)?.verify()?;
+        }
+        Ok(())
+    }
+}
+
+impl DelegateInfoVec {
+    pub fn len(&self) -> usize {
+        self.try_len().unwrap()
+    }
+
+    pub fn try_len(&self) -> Result<usize, Error> {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl DelegateInfoVec {
+    pub fn get(&self, index: usize) -> DelegateInfo {
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<DelegateInfo, Error> {
+        let cur = self.cursor.dynvec_slice_by_index(index)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct AuthorizedLock {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for AuthorizedLock {
+    fn from(cursor: Cursor) -> Self {
+        AuthorizedLock { cursor }
+    }
+}
+
+impl AuthorizedLock {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(33)
+    }
+}
+
+impl AuthorizedLock {
+    pub fn role(&self) -> u8 {
+        self.try_role().unwrap()
+    }
+
+    pub fn try_role(&self) -> Result<u8, Error> {
+        let cur = self.cursor.slice_by_offset(0, 1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl AuthorizedLock {
+    pub fn lock_hash(&self) -> Vec<u8> {
+        self.try_lock_hash().unwrap()
+    }
+
+    pub fn try_lock_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(1, 32)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct AuthorizedLockVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for AuthorizedLockVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl AuthorizedLockVec {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.fixvec_verify(33)
+    }
+}
+
+impl AuthorizedLockVec {
+    pub fn len(&self) -> usize {
+        self.try_len().unwrap()
+    }
+
+    pub fn try_len(&self) -> Result<usize, Error> {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl AuthorizedLockVec {
+    pub fn get(&self, index: usize) -> AuthorizedLock {
+        self.try_get(index).unwrap()
+    }
+
+    pub fn try_get(&self, index: usize) -> Result<AuthorizedLock, Error> {
+        let cur = self.cursor.fixvec_slice_by_index(33, index)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct SelectionRecovery {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for SelectionRecovery {
+    fn from(cursor: Cursor) -> Self {
+        SelectionRecovery { cursor }
+    }
+}
+
+impl SelectionRecovery {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(40)
+    }
+}
+
+impl SelectionRecovery {
+    pub fn recovery_lock_hash(&self) -> Vec<u8> {
+        self.try_recovery_lock_hash().unwrap()
+    }
+
+    pub fn try_recovery_lock_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(0, 32)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl SelectionRecovery {
+    pub fn since_epochs(&self) -> Vec<u8> {
+        self.try_since_epochs().unwrap()
+    }
+
+    pub fn try_since_epochs(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(32, 8)?;
+        Ok(cur.try_into()?)
+    }
+}
+// warning: SelectionRecoveryOpt not implemented for Rust
+pub struct SelectionRecoveryOpt {
+    pub cursor: Cursor,
+}
+impl From<Cursor> for SelectionRecoveryOpt {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl SelectionRecoveryOpt {
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.cursor.option_is_none() {
+            Ok(())
+        } else {
+            SelectionRecovery::from(self.cursor.clone()).verify()
+        }
+    }
+}
+
 pub struct SelectionLockArgs {
     pub cursor: Cursor,
 }
@@ -276,17 +677,62 @@ impl From<Cursor> for SelectionLockArgs {
     }
 }
 
+impl SelectionLockArgs {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(4, false)?;
+        Byte32::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        AuthorizedLockVec::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        SelectionRecoveryOpt::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Ok(())
+    }
+}
+
 impl SelectionLockArgs {
     pub fn omni_lock_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_omni_lock_hash().unwrap()
+    }
+
+    pub fn try_omni_lock_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl SelectionLockArgs {
     pub fn checkpoint_lock_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_checkpoint_lock_hash().unwrap()
+    }
+
+    pub fn try_checkpoint_lock_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl SelectionLockArgs {
+    pub fn authorized_locks(&self) -> AuthorizedLockVec {
+        self.try_authorized_locks().unwrap()
+    }
+
+    pub fn try_authorized_locks(&self) -> Result<AuthorizedLockVec, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl SelectionLockArgs {
+    pub fn recovery(&self) -> Option<SelectionRecovery> {
+        self.try_recovery().unwrap()
+    }
+
+    pub fn try_recovery(&self) -> Result<Option<SelectionRecovery>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        if cur.option_is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(cur.try_into()?))
+        }
     }
 }
 
@@ -300,27 +746,49 @@ impl From<Cursor> for StakeLockArgs {
     }
 }
 
+impl StakeLockArgs {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(3, false)?;
+        Identity::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        IdentityOpt::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Ok(())
+    }
+}
+
 impl StakeLockArgs {
     pub fn admin_identity(&self) -> Identity {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_admin_identity().unwrap()
+    }
+
+    pub fn try_admin_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockArgs {
     pub fn type_id_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_type_id_hash().unwrap()
+    }
+
+    pub fn try_type_id_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockArgs {
     pub fn node_identity(&self) -> Option<Identity> {
-        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        self.try_node_identity().unwrap()
+    }
+
+    pub fn try_node_identity(&self) -> Result<Option<Identity>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
         if cur.option_is_none() {
-            None
+            Ok(None)
         } else {
-            Some(cur.into())
+            Ok(Some(cur.try_into()?))
         }
     }
 }
@@ -335,38 +803,106 @@ impl From<Cursor> for StakeLockCellData {
     }
 }
 
+impl StakeLockCellData {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(8, false)?;
+        self.cursor.table_slice_by_index(0)?.verify_fixed_size(1)?;
+        StakeInfoVec::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        self.cursor.table_slice_by_index(4)?.verify_fixed_size(1)?;
+        Byte8::from(self.cursor.table_slice_by_index(5)?).verify()?;
+        self.cursor.table_slice_by_index(6)?.verify_fixed_size(1)?;
+        DelegateInfoVec::from(self.cursor.table_slice_by_index(7)?).verify()?;
+        Ok(())
+    }
+}
+
 impl StakeLockCellData {
     pub fn version(&self) -> u8 {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_version().unwrap()
+    }
+
+    pub fn try_version(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockCellData {
     pub fn stake_infos(&self) -> StakeInfoVec {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_stake_infos().unwrap()
+    }
+
+    pub fn try_stake_infos(&self) -> Result<StakeInfoVec, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockCellData {
     pub fn checkpoint_type_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(2).unwrap();
-        cur.into()
+        self.try_checkpoint_type_hash().unwrap()
+    }
+
+    pub fn try_checkpoint_type_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockCellData {
     pub fn sudt_type_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(3).unwrap();
-        cur.into()
+        self.try_sudt_type_hash().unwrap()
+    }
+
+    pub fn try_sudt_type_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl StakeLockCellData {
     pub fn quorum_size(&self) -> u8 {
-        let cur = self.cursor.table_slice_by_index(4).unwrap();
-        cur.into()
+        self.try_quorum_size().unwrap()
+    }
+
+    pub fn try_quorum_size(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(4)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl StakeLockCellData {
+    pub fn unbonding_eras(&self) -> Vec<u8> {
+        self.try_unbonding_eras().unwrap()
+    }
+
+    pub fn try_unbonding_eras(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(5)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl StakeLockCellData {
+    pub fn slash_rate(&self) -> u8 {
+        self.try_slash_rate().unwrap()
+    }
+
+    pub fn try_slash_rate(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(6)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl StakeLockCellData {
+    pub fn delegate_infos(&self) -> DelegateInfoVec {
+        self.try_delegate_infos().unwrap()
+    }
+
+    pub fn try_delegate_infos(&self) -> Result<DelegateInfoVec, Error> {
+        let cur = self.cursor.table_slice_by_index(7)?;
+        Ok(cur.try_into()?)
     }
 }
 
@@ -380,17 +916,240 @@ impl From<Cursor> for CheckpointLockArgs {
     }
 }
 
+impl CheckpointLockArgs {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(2, false)?;
+        Identity::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Ok(())
+    }
+}
+
 impl CheckpointLockArgs {
     pub fn admin_identity(&self) -> Identity {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_admin_identity().unwrap()
+    }
+
+    pub fn try_admin_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockArgs {
     pub fn type_id_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_type_id_hash().unwrap()
+    }
+
+    pub fn try_type_id_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct RewardSchedule {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for RewardSchedule {
+    fn from(cursor: Cursor) -> Self {
+        RewardSchedule { cursor }
+    }
+}
+
+impl RewardSchedule {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(4, false)?;
+        self.cursor.table_slice_by_index(0)?.verify_fixed_size(1)?;
+        Byte16::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Ok(())
+    }
+}
+
+impl RewardSchedule {
+    pub fn version(&self) -> u8 {
+        self.try_version().unwrap()
+    }
+
+    pub fn try_version(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl RewardSchedule {
+    pub fn decay_reward(&self) -> Vec<u8> {
+        self.try_decay_reward().unwrap()
+    }
+
+    pub fn try_decay_reward(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl RewardSchedule {
+    pub fn tail_reward(&self) -> Vec<u8> {
+        self.try_tail_reward().unwrap()
+    }
+
+    pub fn try_tail_reward(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl RewardSchedule {
+    pub fn supply_cap(&self) -> Vec<u8> {
+        self.try_supply_cap().unwrap()
+    }
+
+    pub fn try_supply_cap(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+pub struct CheckpointParams {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CheckpointParams {
+    fn from(cursor: Cursor) -> Self {
+        CheckpointParams { cursor }
+    }
+}
+
+impl CheckpointParams {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(4, false)?;
+        Byte8::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte4::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte8::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Ok(())
+    }
+}
+
+impl CheckpointParams {
+    pub fn effective_era(&self) -> Vec<u8> {
+        self.try_effective_era().unwrap()
+    }
+
+    pub fn try_effective_era(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointParams {
+    pub fn unlock_period(&self) -> Vec<u8> {
+        self.try_unlock_period().unwrap()
+    }
+
+    pub fn try_unlock_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointParams {
+    pub fn base_reward(&self) -> Vec<u8> {
+        self.try_base_reward().unwrap()
+    }
+
+    pub fn try_base_reward(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointParams {
+    pub fn half_period(&self) -> Vec<u8> {
+        self.try_half_period().unwrap()
+    }
+
+    pub fn try_half_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
+    }
+}
+// warning: CheckpointParamsOpt not implemented for Rust
+pub struct CheckpointParamsOpt {
+    pub cursor: Cursor,
+}
+impl From<Cursor> for CheckpointParamsOpt {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl CheckpointParamsOpt {
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.cursor.option_is_none() {
+            Ok(())
+        } else {
+            CheckpointParams::from(self.cursor.clone()).verify()
+        }
+    }
+}
+
+pub struct WithdrawalExpiry {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for WithdrawalExpiry {
+    fn from(cursor: Cursor) -> Self {
+        WithdrawalExpiry { cursor }
+    }
+}
+
+impl WithdrawalExpiry {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(40)
+    }
+}
+
+impl WithdrawalExpiry {
+    pub fn expiry_periods(&self) -> Vec<u8> {
+        self.try_expiry_periods().unwrap()
+    }
+
+    pub fn try_expiry_periods(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(0, 8)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl WithdrawalExpiry {
+    pub fn treasury_lock_hash(&self) -> Vec<u8> {
+        self.try_treasury_lock_hash().unwrap()
+    }
+
+    pub fn try_treasury_lock_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(8, 32)?;
+        Ok(cur.try_into()?)
+    }
+}
+// warning: WithdrawalExpiryOpt not implemented for Rust
+pub struct WithdrawalExpiryOpt {
+    pub cursor: Cursor,
+}
+impl From<Cursor> for WithdrawalExpiryOpt {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl WithdrawalExpiryOpt {
+    pub fn verify(&self) -> Result<(), Error> {
+        if self.cursor.option_is_none() {
+            Ok(())
+        } else {
+            WithdrawalExpiry::from(self.cursor.clone()).verify()
+        }
     }
 }
 
@@ -404,101 +1163,246 @@ impl From<Cursor> for CheckpointLockCellData {
     }
 }
 
+impl CheckpointLockCellData {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(19, false)?;
+        self.cursor.table_slice_by_index(0)?.verify_fixed_size(1)?;
+        self.cursor.table_slice_by_index(1)?.verify_fixed_size(1)?;
+        Byte8::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        Byte8::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(4)?).verify()?;
+        Byte4::from(self.cursor.table_slice_by_index(5)?).verify()?;
+        Byte4::from(self.cursor.table_slice_by_index(6)?).verify()?;
+        Byte4::from(self.cursor.table_slice_by_index(7)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(8)?).verify()?;
+        Byte8::from(self.cursor.table_slice_by_index(9)?).verify()?;
+        Byte10::from(self.cursor.table_slice_by_index(10)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(11)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(12)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(13)?).verify()?;
+        RewardSchedule::from(self.cursor.table_slice_by_index(14)?).verify()?;
+        Byte16::from(self.cursor.table_slice_by_index(15)?).verify()?;
+        CheckpointParamsOpt::from(self.cursor.table_slice_by_index(16)?).verify()?;
+        self.cursor.table_slice_by_index(17)?.verify_fixed_size(1)?;
+        WithdrawalExpiryOpt::from(self.cursor.table_slice_by_index(18)?).verify()?;
+        Ok(())
+    }
+}
+
 impl CheckpointLockCellData {
     pub fn version(&self) -> u8 {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_version().unwrap()
+    }
+
+    pub fn try_version(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn state(&self) -> u8 {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_state().unwrap()
+    }
+
+    pub fn try_state(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn period(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(2).unwrap();
-        cur.into()
+        self.try_period().unwrap()
+    }
+
+    pub fn try_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn era(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(3).unwrap();
-        cur.into()
+        self.try_era().unwrap()
+    }
+
+    pub fn try_era(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn block_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(4).unwrap();
-        cur.into()
+        self.try_block_hash().unwrap()
+    }
+
+    pub fn try_block_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(4)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn period_interval(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(5).unwrap();
-        cur.into()
+        self.try_period_interval().unwrap()
+    }
+
+    pub fn try_period_interval(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(5)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn era_period(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(6).unwrap();
-        cur.into()
+        self.try_era_period().unwrap()
+    }
+
+    pub fn try_era_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(6)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn unlock_period(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(7).unwrap();
-        cur.into()
+        self.try_unlock_period().unwrap()
+    }
+
+    pub fn try_unlock_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(7)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn base_reward(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(8).unwrap();
-        cur.into()
+        self.try_base_reward().unwrap()
+    }
+
+    pub fn try_base_reward(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(8)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn half_period(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(9).unwrap();
-        cur.into()
+        self.try_half_period().unwrap()
+    }
+
+    pub fn try_half_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(9)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn common_ref(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(10).unwrap();
-        cur.into()
+        self.try_common_ref().unwrap()
+    }
+
+    pub fn try_common_ref(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(10)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn sudt_type_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(11).unwrap();
-        cur.into()
+        self.try_sudt_type_hash().unwrap()
+    }
+
+    pub fn try_sudt_type_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(11)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn stake_type_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(12).unwrap();
-        cur.into()
+        self.try_stake_type_hash().unwrap()
+    }
+
+    pub fn try_stake_type_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(12)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl CheckpointLockCellData {
     pub fn withdrawal_lock_code_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(13).unwrap();
-        cur.into()
+        self.try_withdrawal_lock_code_hash().unwrap()
+    }
+
+    pub fn try_withdrawal_lock_code_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(13)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointLockCellData {
+    pub fn reward_schedule(&self) -> RewardSchedule {
+        self.try_reward_schedule().unwrap()
+    }
+
+    pub fn try_reward_schedule(&self) -> Result<RewardSchedule, Error> {
+        let cur = self.cursor.table_slice_by_index(14)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointLockCellData {
+    pub fn minted_amount(&self) -> Vec<u8> {
+        self.try_minted_amount().unwrap()
+    }
+
+    pub fn try_minted_amount(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(15)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointLockCellData {
+    pub fn pending_params(&self) -> Option<CheckpointParams> {
+        self.try_pending_params().unwrap()
+    }
+
+    pub fn try_pending_params(&self) -> Result<Option<CheckpointParams>, Error> {
+        let cur = self.cursor.table_slice_by_index(16)?;
+        if cur.option_is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(cur.try_into()?))
+        }
+    }
+}
+
+impl CheckpointLockCellData {
+    pub fn reward_mode(&self) -> u8 {
+        self.try_reward_mode().unwrap()
+    }
+
+    pub fn try_reward_mode(&self) -> Result<u8, Error> {
+        let cur = self.cursor.table_slice_by_index(17)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl CheckpointLockCellData {
+    pub fn withdrawal_expiry(&self) -> Option<WithdrawalExpiry> {
+        self.try_withdrawal_expiry().unwrap()
+    }
+
+    pub fn try_withdrawal_expiry(&self) -> Result<Option<WithdrawalExpiry>, Error> {
+        let cur = self.cursor.table_slice_by_index(18)?;
+        if cur.option_is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(cur.try_into()?))
+        }
     }
 }
 
@@ -512,27 +1416,103 @@ impl From<Cursor> for WithdrawalLockArgs {
     }
 }
 
+impl WithdrawalLockArgs {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.table_verify(4, false)?;
+        Identity::from(self.cursor.table_slice_by_index(0)?).verify()?;
+        Byte32::from(self.cursor.table_slice_by_index(1)?).verify()?;
+        IdentityOpt::from(self.cursor.table_slice_by_index(2)?).verify()?;
+        WithdrawalExpiryOpt::from(self.cursor.table_slice_by_index(3)?).verify()?;
+        Ok(())
+    }
+}
+
 impl WithdrawalLockArgs {
     pub fn admin_identity(&self) -> Identity {
-        let cur = self.cursor.table_slice_by_index(0).unwrap();
-        cur.into()
+        self.try_admin_identity().unwrap()
+    }
+
+    pub fn try_admin_identity(&self) -> Result<Identity, Error> {
+        let cur = self.cursor.table_slice_by_index(0)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl WithdrawalLockArgs {
     pub fn checkpoint_cell_type_hash(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
-        cur.into()
+        self.try_checkpoint_cell_type_hash().unwrap()
+    }
+
+    pub fn try_checkpoint_cell_type_hash(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.table_slice_by_index(1)?;
+        Ok(cur.try_into()?)
     }
 }
 
 impl WithdrawalLockArgs {
     pub fn node_identity(&self) -> Option<Identity> {
-        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        self.try_node_identity().unwrap()
+    }
+
+    pub fn try_node_identity(&self) -> Result<Option<Identity>, Error> {
+        let cur = self.cursor.table_slice_by_index(2)?;
+        if cur.option_is_none() {
+            Ok(None)
+        } else {
+            Ok(Some(cur.try_into()?))
+        }
+    }
+}
+
+impl WithdrawalLockArgs {
+    pub fn expiry(&self) -> Option<WithdrawalExpiry> {
+        self.try_expiry().unwrap()
+    }
+
+    pub fn try_expiry(&self) -> Result<Option<WithdrawalExpiry>, Error> {
+        let cur = self.cursor.table_slice_by_index(3)?;
         if cur.option_is_none() {
-            None
+            Ok(None)
         } else {
-            Some(cur.into())
+            Ok(Some(cur.try_into()?))
         }
     }
 }
+
+pub struct WithdrawalLockCellData {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for WithdrawalLockCellData {
+    fn from(cursor: Cursor) -> Self {
+        WithdrawalLockCellData { cursor }
+    }
+}
+
+impl WithdrawalLockCellData {
+    pub fn verify(&self) -> Result<(), Error> {
+        self.cursor.verify_fixed_size(24)
+    }
+}
+
+impl WithdrawalLockCellData {
+    pub fn amount(&self) -> Vec<u8> {
+        self.try_amount().unwrap()
+    }
+
+    pub fn try_amount(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(0, 16)?;
+        Ok(cur.try_into()?)
+    }
+}
+
+impl WithdrawalLockCellData {
+    pub fn period(&self) -> Vec<u8> {
+        self.try_period().unwrap()
+    }
+
+    pub fn try_period(&self) -> Result<Vec<u8>, Error> {
+        let cur = self.cursor.slice_by_offset(16, 8)?;
+        Ok(cur.try_into()?)
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+
+use crate::rlp::{Error, Rlp};
+
+// field indices of the Axon block header
+pub(crate) const HEADER_FIELD_COUNT: usize = 19;
+const HEADER_PROPOSER: usize = 1;
+pub(crate) const HEADER_NUMBER: usize = 9;
+const HEADER_LAST_CHECKPOINT_BLOCK_HASH: usize = 17;
+
+// field indices of the Axon proof
+const PROOF_FIELD_COUNT: usize = 5;
+const PROOF_NUMBER: usize = 0;
+const PROOF_BLOCK_HASH: usize = 2;
+const PROOF_SIGNATURE: usize = 3;
+const PROOF_BITMAP: usize = 4;
+
+/// L2 checkpoint submitted in the witness lock of checkpoint mode
+///
+/// The checkpoint is encoded as `rlp([header, proof])`, where `header` is the Axon block header
+/// `[prev_hash, proposer, state_root, transactions_root, signed_txs_hash, receipts_root, log_bloom,
+/// difficulty, timestamp, number, gas_used, gas_limit, extra_data, mixed_hash, nonce,
+/// base_fee_per_gas, proof, last_checkpoint_block_hash, chain_id]` and `proof` is the consensus
+/// proof of that block `[number, round, block_hash, signature, bitmap]`.
+pub struct Checkpoint {
+    header: Vec<u8>,
+    proposer: [u8; 20],
+    block_number: u64,
+    last_checkpoint_block_hash: [u8; 32],
+    proof_number: u64,
+    block_hash: [u8; 32],
+    signature: Vec<u8>,
+    bitmap: Vec<u8>,
+}
+
+impl Checkpoint {
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        let checkpoint = Rlp::new(data)?.items_exact(2)?;
+        let header = checkpoint[0].items_exact(HEADER_FIELD_COUNT)?;
+        let proof = checkpoint[1].items_exact(PROOF_FIELD_COUNT)?;
+        Ok(Checkpoint {
+            header: checkpoint[0].as_raw().to_vec(),
+            proposer: header[HEADER_PROPOSER].as_array()?,
+            block_number: header[HEADER_NUMBER].as_u64()?,
+            last_checkpoint_block_hash: header[HEADER_LAST_CHECKPOINT_BLOCK_HASH].as_array()?,
+            proof_number: proof[PROOF_NUMBER].as_u64()?,
+            block_hash: proof[PROOF_BLOCK_HASH].as_array()?,
+            signature: proof[PROOF_SIGNATURE].data()?.to_vec(),
+            bitmap: proof[PROOF_BITMAP].data()?.to_vec(),
+        })
+    }
+
+    /// RLP encoded block header
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    pub fn proposer(&self) -> &[u8; 20] {
+        &self.proposer
+    }
+
+    pub fn block_number(&self) -> u64 {
+        self.block_number
+    }
+
+    pub fn last_checkpoint_block_hash(&self) -> &[u8; 32] {
+        &self.last_checkpoint_block_hash
+    }
+
+    /// Block number the proof is signed for, which must be the number of the header
+    pub fn proof_number(&self) -> u64 {
+        self.proof_number
+    }
+
+    pub fn block_hash(&self) -> &[u8; 32] {
+        &self.block_hash
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+
+    pub fn bitmap(&self) -> &[u8] {
+        &self.bitmap
+    }
+}
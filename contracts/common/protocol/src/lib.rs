@@ -4,5 +4,36 @@
 #![feature(alloc_error_handler)]
 #![feature(panic_info_message)]
 
+extern crate alloc;
+
+#[allow(clippy::all)]
 pub mod axon;
-pub use molecule2::{read_at, Cursor};
+pub mod checkpoint;
+pub mod mode;
+pub mod rlp;
+pub mod slash;
+pub mod stake;
+pub mod sudt;
+pub mod withdrawal;
+pub use molecule2::{read_at, Cursor, Error as MoleculeError};
+
+/// Read a little-endian u128 from 16 bytes
+pub fn bytes_to_u128(bytes: &[u8]) -> u128 {
+    let mut array: [u8; 16] = [0u8; 16];
+    array.copy_from_slice(bytes);
+    u128::from_le_bytes(array)
+}
+
+/// Read a little-endian u64 from 8 bytes
+pub fn bytes_to_u64(bytes: &[u8]) -> u64 {
+    let mut array: [u8; 8] = [0u8; 8];
+    array.copy_from_slice(bytes);
+    u64::from_le_bytes(array)
+}
+
+/// Read a little-endian u32 from 4 bytes
+pub fn bytes_to_u32(bytes: &[u8]) -> u32 {
+    let mut array: [u8; 4] = [0u8; 4];
+    array.copy_from_slice(bytes);
+    u32::from_le_bytes(array)
+}
@@ -0,0 +1,23 @@
+//! Mode bytes in witness input_type, which are shared by all locks so that one action is selected
+//! by the same byte in every lock it touches, e.g. stake lock and withdrawal lock in slash mode
+
+/// Admin mode of checkpoint lock and stake lock
+pub const ADMIN: u8 = 0;
+/// Burn mode of stake lock under a node identity and withdrawal lock
+pub const BURN: u8 = 0;
+/// Checkpoint mode of checkpoint lock
+pub const CHECKPOINT: u8 = 1;
+/// Companion mode of stake lock under a node identity
+pub const COMPANION: u8 = 1;
+/// Unlock mode of withdrawal lock
+pub const UNLOCK: u8 = 1;
+/// Reconfigure mode of checkpoint lock
+pub const RECONFIGURE: u8 = 2;
+/// Unstake mode of stake lock
+pub const UNSTAKE: u8 = 2;
+/// Slash mode of stake lock and withdrawal lock
+pub const SLASH: u8 = 3;
+/// Delegate mode of stake lock
+pub const DELEGATE: u8 = 4;
+/// Sweep mode of withdrawal lock
+pub const SWEEP: u8 = 5;
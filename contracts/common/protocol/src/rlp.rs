@@ -0,0 +1,155 @@
+use alloc::vec::Vec;
+
+/// Errors raised while decoding RLP data
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Truncated,
+    NonCanonical,
+    ExpectedList,
+    ExpectedBytes,
+    ItemCount,
+    ItemLength,
+    TrailingBytes,
+}
+
+/// A single RLP item, either a byte string or a list
+#[derive(Clone, Copy)]
+pub struct Rlp<'a> {
+    raw: &'a [u8],
+    payload: &'a [u8],
+    is_list: bool,
+}
+
+impl<'a> Rlp<'a> {
+    /// decode `data` as exactly one RLP item
+    pub fn new(data: &'a [u8]) -> Result<Self, Error> {
+        let (item, rest) = Self::decode_item(data)?;
+        if !rest.is_empty() {
+            return Err(Error::TrailingBytes);
+        }
+        Ok(item)
+    }
+
+    /// decode the first RLP item in `data` and return the remaining bytes
+    fn decode_item(data: &'a [u8]) -> Result<(Self, &'a [u8]), Error> {
+        let prefix = *data.first().ok_or(Error::Truncated)?;
+        let (header_size, payload_size, is_list) = match prefix {
+            0x00..=0x7f => (0, 1, false),
+            0x80..=0xb7 => (1, (prefix - 0x80) as usize, false),
+            0xb8..=0xbf => {
+                let size = decode_size(&data[1..], (prefix - 0xb7) as usize)?;
+                (1 + (prefix - 0xb7) as usize, size, false)
+            }
+            0xc0..=0xf7 => (1, (prefix - 0xc0) as usize, true),
+            0xf8..=0xff => {
+                let size = decode_size(&data[1..], (prefix - 0xf7) as usize)?;
+                (1 + (prefix - 0xf7) as usize, size, true)
+            }
+        };
+        let total_size = header_size
+            .checked_add(payload_size)
+            .ok_or(Error::Truncated)?;
+        if data.len() < total_size {
+            return Err(Error::Truncated);
+        }
+        let payload = if header_size == 0 {
+            &data[..1]
+        } else {
+            &data[header_size..total_size]
+        };
+        // a single byte below 0x80 must be encoded as itself
+        if !is_list && header_size == 1 && payload_size == 1 && payload[0] < 0x80 {
+            return Err(Error::NonCanonical);
+        }
+        let item = Rlp {
+            raw: &data[..total_size],
+            payload,
+            is_list,
+        };
+        Ok((item, &data[total_size..]))
+    }
+
+    /// the whole encoded item, including its header
+    pub fn as_raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    pub fn is_list(&self) -> bool {
+        self.is_list
+    }
+
+    /// payload of a byte string item
+    pub fn data(&self) -> Result<&'a [u8], Error> {
+        if self.is_list {
+            return Err(Error::ExpectedBytes);
+        }
+        Ok(self.payload)
+    }
+
+    /// items of a list item
+    pub fn items(&self) -> Result<Vec<Rlp<'a>>, Error> {
+        if !self.is_list {
+            return Err(Error::ExpectedList);
+        }
+        let mut items = Vec::new();
+        let mut rest = self.payload;
+        while !rest.is_empty() {
+            let (item, remain) = Self::decode_item(rest)?;
+            items.push(item);
+            rest = remain;
+        }
+        Ok(items)
+    }
+
+    /// items of a list item which must contain exactly `count` items
+    pub fn items_exact(&self, count: usize) -> Result<Vec<Rlp<'a>>, Error> {
+        let items = self.items()?;
+        if items.len() != count {
+            return Err(Error::ItemCount);
+        }
+        Ok(items)
+    }
+
+    /// byte string item of exactly `N` bytes
+    pub fn as_array<const N: usize>(&self) -> Result<[u8; N], Error> {
+        let data = self.data()?;
+        if data.len() != N {
+            return Err(Error::ItemLength);
+        }
+        let mut array = [0u8; N];
+        array.copy_from_slice(data);
+        Ok(array)
+    }
+
+    /// big-endian unsigned integer without leading zeros
+    pub fn as_u64(&self) -> Result<u64, Error> {
+        let data = self.data()?;
+        if data.len() > 8 {
+            return Err(Error::ItemLength);
+        }
+        if data.first() == Some(&0) {
+            return Err(Error::NonCanonical);
+        }
+        Ok(data
+            .iter()
+            .fold(0u64, |value, byte| (value << 8) | *byte as u64))
+    }
+}
+
+fn decode_size(data: &[u8], size_of_size: usize) -> Result<usize, Error> {
+    if data.len() < size_of_size {
+        return Err(Error::Truncated);
+    }
+    let bytes = &data[..size_of_size];
+    if bytes[0] == 0 || size_of_size > core::mem::size_of::<usize>() {
+        return Err(Error::NonCanonical);
+    }
+    let size = bytes
+        .iter()
+        .fold(0usize, |value, byte| (value << 8) | *byte as usize);
+    // sizes below 56 must use the short form
+    if size < 56 {
+        return Err(Error::NonCanonical);
+    }
+    Ok(size)
+}
@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+
+use crate::checkpoint::{HEADER_FIELD_COUNT, HEADER_NUMBER};
+use crate::rlp::{Error, Rlp};
+
+/// slash_rate of stake_data is a percentage, which can not exceed 100
+pub const MAX_SLASH_RATE: u8 = 100;
+
+/// Get `slash_rate` percent of `amount`, which is never more than `amount`, or None if
+/// `slash_rate` exceeds MAX_SLASH_RATE
+pub fn get_slashed_amount(amount: u128, slash_rate: u8) -> Option<u128> {
+    if slash_rate > MAX_SLASH_RATE {
+        return None;
+    }
+    let slash_rate = slash_rate as u128;
+    Some(amount / 100 * slash_rate + amount % 100 * slash_rate / 100)
+}
+
+/// Vote of a validator, which is its BLS signature of the keccak hash of an Axon block header
+pub struct Vote {
+    header: Vec<u8>,
+    block_number: u64,
+    signature: Vec<u8>,
+}
+
+impl Vote {
+    fn decode(vote: &Rlp) -> Result<Self, Error> {
+        let vote = vote.items_exact(2)?;
+        let header = vote[0].items_exact(HEADER_FIELD_COUNT)?;
+        Ok(Vote {
+            header: vote[0].as_raw().to_vec(),
+            block_number: header[HEADER_NUMBER].as_u64()?,
+            signature: vote[1].data()?.to_vec(),
+        })
+    }
+
+    /// RLP encoded block header
+    pub fn header(&self) -> &[u8] {
+        &self.header
+    }
+
+    pub fn block_number(&self) -> u64 {
+        self.block_number
+    }
+
+    pub fn signature(&self) -> &[u8] {
+        &self.signature
+    }
+}
+
+/// Proof of a validator voting for two different blocks of the same height
+///
+/// The proof is submitted in the witness lock of slash mode and encoded as
+/// `rlp([[header, signature], [header, signature]])`.
+pub struct SlashProof {
+    votes: [Vote; 2],
+}
+
+impl SlashProof {
+    pub fn decode(data: &[u8]) -> Result<Self, Error> {
+        let votes = Rlp::new(data)?.items_exact(2)?;
+        Ok(SlashProof {
+            votes: [Vote::decode(&votes[0])?, Vote::decode(&votes[1])?],
+        })
+    }
+
+    pub fn votes(&self) -> &[Vote; 2] {
+        &self.votes
+    }
+}
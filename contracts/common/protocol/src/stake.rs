@@ -0,0 +1,243 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+use crate::{
+    axon::{DelegateInfo, DelegateInfoVec, StakeInfo, StakeInfoVec, StakeLockCellData},
+    bytes_to_u128, bytes_to_u64, MoleculeError,
+};
+
+/// Errors raised while reading stake_data, which contracts map to their own error codes
+#[derive(Debug)]
+pub enum Error {
+    Molecule(MoleculeError),
+    WeightOverflow,
+}
+
+impl From<MoleculeError> for Error {
+    fn from(err: MoleculeError) -> Self {
+        Self::Molecule(err)
+    }
+}
+
+fn identity_bytes(stake_info: &StakeInfo) -> Result<Vec<u8>, Error> {
+    Ok(Vec::<u8>::try_from(stake_info.try_identity()?.cursor)?)
+}
+
+/// Collect stake_infos which have taken effect in `era`
+///
+/// A StakeInfo takes effect from its inauguration_era, and for each identity only the latest
+/// StakeInfo which has taken effect is kept, so a node can update its stake by appending a new
+/// StakeInfo. Stake_infos keep the order in which their identities first appear.
+pub fn get_stake_infos_by_era(
+    stake_infos: &StakeInfoVec,
+    era: u64,
+) -> Result<Vec<StakeInfo>, Error> {
+    let mut applied: Vec<(Vec<u8>, u64, StakeInfo)> = Vec::new();
+    for i in 0..stake_infos.try_len()? {
+        let stake_info = stake_infos.try_get(i)?;
+        let inauguration_era = bytes_to_u64(&stake_info.try_inauguration_era()?);
+        if inauguration_era > era {
+            continue;
+        }
+        let identity = identity_bytes(&stake_info)?;
+        match applied.iter_mut().find(|(value, _, _)| value == &identity) {
+            Some(entry) => {
+                if inauguration_era >= entry.1 {
+                    entry.1 = inauguration_era;
+                    entry.2 = stake_info;
+                }
+            }
+            None => applied.push((identity, inauguration_era, stake_info)),
+        }
+    }
+    Ok(applied
+        .into_iter()
+        .map(|(_, _, stake_info)| stake_info)
+        .collect())
+}
+
+/// Collect delegate_infos which have taken effect in `era`
+///
+/// For each pair of delegator and validator only the latest DelegateInfo which has taken effect
+/// is kept, so a delegator can update its delegation by appending a new DelegateInfo.
+pub fn get_delegate_infos_by_era(
+    delegate_infos: &DelegateInfoVec,
+    era: u64,
+) -> Result<Vec<DelegateInfo>, Error> {
+    let mut applied: Vec<(Vec<u8>, Vec<u8>, u64, DelegateInfo)> = Vec::new();
+    for i in 0..delegate_infos.try_len()? {
+        let delegate_info = delegate_infos.try_get(i)?;
+        let inauguration_era = bytes_to_u64(&delegate_info.try_inauguration_era()?);
+        if inauguration_era > era {
+            continue;
+        }
+        let delegator = Vec::<u8>::try_from(delegate_info.try_delegator_identity()?.cursor)?;
+        let validator = Vec::<u8>::try_from(delegate_info.try_validator_identity()?.cursor)?;
+        match applied
+            .iter_mut()
+            .find(|(value, other, _, _)| value == &delegator && other == &validator)
+        {
+            Some(entry) => {
+                if inauguration_era >= entry.2 {
+                    entry.2 = inauguration_era;
+                    entry.3 = delegate_info;
+                }
+            }
+            None => applied.push((delegator, validator, inauguration_era, delegate_info)),
+        }
+    }
+    Ok(applied
+        .into_iter()
+        .map(|(_, _, _, delegate_info)| delegate_info)
+        .collect())
+}
+
+/// Collect delegate_infos of the validator which have taken effect in `era`
+pub fn get_delegators_by_era(
+    stake_data: &StakeLockCellData,
+    validator: &StakeInfo,
+    era: u64,
+) -> Result<Vec<DelegateInfo>, Error> {
+    let validator_identity = identity_bytes(validator)?;
+    let mut delegators = Vec::new();
+    for delegate_info in get_delegate_infos_by_era(&stake_data.try_delegate_infos()?, era)? {
+        if Vec::<u8>::try_from(delegate_info.try_validator_identity()?.cursor)?
+            == validator_identity
+            && bytes_to_u128(&delegate_info.try_amount()?) > 0
+        {
+            delegators.push(delegate_info);
+        }
+    }
+    Ok(delegators)
+}
+
+fn get_weight(stake_info: &StakeInfo, delegators: &[DelegateInfo]) -> Result<u128, Error> {
+    delegators.iter().try_fold(
+        bytes_to_u128(&stake_info.try_stake_amount()?),
+        |weight, delegate_info| {
+            weight
+                .checked_add(bytes_to_u128(&delegate_info.try_amount()?))
+                .ok_or(Error::WeightOverflow)
+        },
+    )
+}
+
+/// Weight of the validator in `era`, which is its stake_amount plus AT delegated to it
+pub fn get_validator_weight(
+    stake_data: &StakeLockCellData,
+    validator: &StakeInfo,
+    era: u64,
+) -> Result<u128, Error> {
+    get_weight(
+        validator,
+        &get_delegators_by_era(stake_data, validator, era)?,
+    )
+}
+
+/// Compute `a * b / c` rounding down, where `b` must not be greater than `c`
+pub fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    if c == 0 {
+        return 0;
+    }
+    // 256 bits product of `a` and `b`, which is split into `high` and `low`
+    let (a_high, a_low) = (a >> 64, a & u64::MAX as u128);
+    let (b_high, b_low) = (b >> 64, b & u64::MAX as u128);
+    let (middle, middle_carry) = (a_high * b_low).overflowing_add(a_low * b_high);
+    let (low, low_carry) = (a_low * b_low).overflowing_add(middle << 64);
+    let high =
+        a_high * b_high + (middle >> 64) + ((middle_carry as u128) << 64) + low_carry as u128;
+    // long division, and the quotient fits in 128 bits since `b` is not greater than `c`
+    let mut quotient = 0u128;
+    let mut remainder = 0u128;
+    for i in (0..256).rev() {
+        let bit = if i >= 128 {
+            (high >> (i - 128)) & 1
+        } else {
+            (low >> i) & 1
+        };
+        let overflow = remainder >> 127 == 1;
+        remainder = (remainder << 1) | bit;
+        if overflow || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            if i < 128 {
+                quotient |= 1 << i;
+            }
+        }
+    }
+    quotient
+}
+
+/// Rewards of delegators, each of which is paired with the identity of its delegator
+pub type DelegatorRewards = Vec<(Vec<u8>, u128)>;
+
+/// Split `reward` of a validator between the validator and its delegators in `era`
+///
+/// The validator takes commission_rate percent of `reward` as commission, and the rest is shared
+/// by the validator and its delegators in proportion to their stake. Return the reward of the
+/// validator, which also takes the dust of rounding, and rewards of delegators.
+pub fn split_reward(
+    stake_data: &StakeLockCellData,
+    validator: &StakeInfo,
+    reward: u128,
+    era: u64,
+) -> Result<(u128, DelegatorRewards), Error> {
+    let delegators = get_delegators_by_era(stake_data, validator, era)?;
+    let weight = get_weight(validator, &delegators)?;
+    let commission_rate = core::cmp::min(validator.try_commission_rate()?, 100) as u128;
+    let shared_reward = reward - mul_div(reward, commission_rate, 100);
+    let delegator_rewards = delegators
+        .into_iter()
+        .map(|delegate_info| {
+            let delegator_reward = mul_div(
+                shared_reward,
+                bytes_to_u128(&delegate_info.try_amount()?),
+                weight,
+            );
+            Ok((
+                Vec::<u8>::try_from(delegate_info.try_delegator_identity()?.cursor)?,
+                delegator_reward,
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    let validator_reward = delegator_rewards
+        .iter()
+        .fold(reward, |reward, (_, delegator_reward)| {
+            reward - delegator_reward
+        });
+    Ok((validator_reward, delegator_rewards))
+}
+
+/// Collect the validator set of `era`, which is capped by quorum_size
+///
+/// Both stake and checkpoint contracts rely on this function, stake contract only slashes a
+/// validator of the era in which the slashed votes are signed, and L2_bitmap in a checkpoint marks
+/// validators in the returned order. Validators are the top quorum_size nodes ordered by weight
+/// descending, which is stake_amount plus AT delegated to the node, and nodes with equal weight
+/// are ordered by identity ascending. A node whose latest stake_amount is zero has unstaked and
+/// is not a validator.
+pub fn get_validators_by_era(
+    stake_data: &StakeLockCellData,
+    era: u64,
+) -> Result<Vec<StakeInfo>, Error> {
+    let mut validators = Vec::new();
+    for stake_info in get_stake_infos_by_era(&stake_data.try_stake_infos()?, era)? {
+        if bytes_to_u128(&stake_info.try_stake_amount()?) == 0 {
+            continue;
+        }
+        let delegators = get_delegators_by_era(stake_data, &stake_info, era)?;
+        validators.push((
+            get_weight(&stake_info, &delegators)?,
+            identity_bytes(&stake_info)?,
+            stake_info,
+        ));
+    }
+    validators.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    let quorum_size = stake_data.try_quorum_size()? as usize;
+    if quorum_size > 0 {
+        validators.truncate(quorum_size);
+    }
+    Ok(validators
+        .into_iter()
+        .map(|(_, _, stake_info)| stake_info)
+        .collect())
+}
@@ -0,0 +1,54 @@
+use ckb_std::{
+    ckb_constants::Source,
+    error::SysError,
+    high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash, QueryIter},
+};
+
+use crate::bytes_to_u128;
+
+/// Errors raised while collecting sUDT cells, which contracts map to their own error codes
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Sys(SysError),
+    BadSudtDataFormat,
+    BadWithdrawalData,
+    AmountOverflow,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        Self::Sys(err)
+    }
+}
+
+/// Read the uint128_t amount at the beginning of sUDT cell data
+pub fn get_amount(data: &[u8]) -> Result<u128, Error> {
+    if data.len() < 16 {
+        return Err(Error::BadSudtDataFormat);
+    }
+    Ok(bytes_to_u128(&data[..16]))
+}
+
+/// Sum up the sUDT amount of cells in `source` whose type hash is `sudt_type_hash`, and whose
+/// lock hash is `lock_hash` if it is given
+pub fn get_total_amount(
+    lock_hash: Option<&[u8]>,
+    sudt_type_hash: &[u8],
+    source: Source,
+) -> Result<u128, Error> {
+    let mut sudt = 0u128;
+    for (i, type_hash) in QueryIter::new(load_cell_type_hash, source).enumerate() {
+        if type_hash.as_ref().map(|hash| &hash[..]) != Some(sudt_type_hash) {
+            continue;
+        }
+        if let Some(lock_hash) = lock_hash {
+            if &load_cell_lock_hash(i, source)?[..] != lock_hash {
+                continue;
+            }
+        }
+        sudt = sudt
+            .checked_add(get_amount(&load_cell_data(i, source)?)?)
+            .ok_or(Error::AmountOverflow)?;
+    }
+    Ok(sudt)
+}
@@ -0,0 +1,104 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, core::ScriptHashType, packed::Script, prelude::*},
+    high_level::{load_cell_data, load_cell_lock, load_cell_type_hash, QueryIter},
+};
+use molecule2::Cursor;
+
+use crate::{axon::WithdrawalLockCellData, bytes_to_u128, bytes_to_u64, sudt::Error};
+
+/// Size of the molecule encoded WithdrawalLockCellData, whose amount is also the sUDT amount of
+/// the Withdrawal AT cell
+pub const CELL_DATA_SIZE: usize = 24;
+
+// serialize `fields` as a molecule table
+fn pack_table(fields: &[&[u8]]) -> Vec<u8> {
+    let header_size = 4 * (fields.len() + 1);
+    let total_size = header_size + fields.iter().map(|field| field.len()).sum::<usize>();
+    let mut table = Vec::with_capacity(total_size);
+    table.extend_from_slice(&(total_size as u32).to_le_bytes());
+    let mut offset = header_size;
+    for field in fields {
+        table.extend_from_slice(&(offset as u32).to_le_bytes());
+        offset += field.len();
+    }
+    for field in fields {
+        table.extend_from_slice(field);
+    }
+    table
+}
+
+/// Build the molecule encoded WithdrawalLockArgs
+///
+/// `admin_identity` and `node_identity` are molecule encoded Identity tables and `expiry` is a
+/// molecule encoded WithdrawalExpiry struct, an absent option is encoded as an empty option.
+pub fn lock_args(
+    admin_identity: &[u8],
+    checkpoint_cell_type_hash: &[u8],
+    node_identity: Option<&[u8]>,
+    expiry: Option<&[u8]>,
+) -> Vec<u8> {
+    pack_table(&[
+        admin_identity,
+        checkpoint_cell_type_hash,
+        node_identity.unwrap_or(&[]),
+        expiry.unwrap_or(&[]),
+    ])
+}
+
+/// Build the withdrawal lock script, which is referenced by the type hash of its code
+pub fn lock_script(withdrawal_lock_code_hash: &[u8], lock_args: &[u8]) -> Script {
+    let mut code_hash = [0u8; 32];
+    code_hash.copy_from_slice(withdrawal_lock_code_hash);
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(lock_args.to_vec()).pack())
+        .build()
+}
+
+/// Collect (amount, period) of Withdrawal AT cells in `source` which are locked by `lock` and typed
+/// by `sudt_type_hash`
+pub fn get_withdrawal_cells(
+    lock: &Script,
+    sudt_type_hash: &[u8],
+    source: Source,
+) -> Result<Vec<(u128, u64)>, Error> {
+    let mut withdrawal_cells = Vec::new();
+    for (i, cell_lock) in QueryIter::new(load_cell_lock, source).enumerate() {
+        if cell_lock.as_slice() != lock.as_slice()
+            || load_cell_type_hash(i, source)?
+                .as_ref()
+                .map(|hash| &hash[..])
+                != Some(sudt_type_hash)
+        {
+            continue;
+        }
+        let data = load_cell_data(i, source)?;
+        if data.len() != CELL_DATA_SIZE {
+            return Err(Error::BadWithdrawalData);
+        }
+        let withdrawal_data: WithdrawalLockCellData = Cursor::from(data).into();
+        withdrawal_cells.push((
+            bytes_to_u128(&withdrawal_data.amount()),
+            bytes_to_u64(&withdrawal_data.period()),
+        ));
+    }
+    Ok(withdrawal_cells)
+}
+
+/// Sum up AT of Withdrawal AT cells in `source` which are locked by `lock` and typed by
+/// `sudt_type_hash` by their period
+pub fn get_withdrawal_buckets(
+    lock: &Script,
+    sudt_type_hash: &[u8],
+    source: Source,
+) -> Result<BTreeMap<u64, u128>, Error> {
+    let mut buckets: BTreeMap<u64, u128> = BTreeMap::new();
+    for (amount, period) in get_withdrawal_cells(lock, sudt_type_hash, source)? {
+        let bucket = buckets.entry(period).or_insert(0);
+        *bucket = bucket.checked_add(amount).ok_or(Error::AmountOverflow)?;
+    }
+    Ok(buckets)
+}
@@ -14,5 +14,5 @@ extern "C" {
 
 pub fn verify_signature(pubkey_hash: &mut Vec<u8>) -> bool {
     let error_code = unsafe { verify_secp256k1_blake160_sighash_all(pubkey_hash.as_mut_ptr()) };
-    return error_code == 0;
+    error_code == 0
 }
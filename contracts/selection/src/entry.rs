@@ -1,49 +1,186 @@
 // Import from `core` instead of from `std` since we are in no-std mode
 use core::result::Result;
 
+// Import heap related library from `alloc`
+// https://doc.rust-lang.org/alloc/index.html
+use alloc::{vec, vec::Vec};
+
 // Import CKB syscalls and structures
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::{bytes::Bytes, prelude::*},
     debug,
-    high_level::{load_cell_lock_hash, load_script, QueryIter},
+    error::SysError,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash,
+        load_input_since, load_script, load_script_hash, load_witness_args, QueryIter,
+    },
+    syscalls::load_witness,
 };
 
 use crate::error::Error;
-use protocol::{axon, Cursor};
+use protocol::{axon, bytes_to_u64, Cursor};
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, PartialEq)]
+enum ROLE {
+    OWNER,
+    CHECKPOINT,
+    EMERGENCY,
+    RECOVERY,
+}
+
+// flags of relative since in epoch
+const SINCE_RELATIVE_FLAG: u64 = 0x8000_0000_0000_0000;
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+const SINCE_EPOCH_NUMBER_MASK: u64 = 0xff_ffff;
+
+fn get_role(role: u8) -> Result<ROLE, Error> {
+    match role {
+        0 => Ok(ROLE::OWNER),
+        1 => Ok(ROLE::CHECKPOINT),
+        2 => Ok(ROLE::EMERGENCY),
+        3 => Ok(ROLE::RECOVERY),
+        _ => Err(Error::UnknownRole),
+    }
+}
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
     let args: Bytes = script.args().unpack();
 
-    // extract omni and checkpoint lock_hash from script_args
+    // extract authorized lock_hashes and their roles from script_args, omni and checkpoint
+    // lock_hash are authorized as owner and checkpoint
     let selection_args: axon::SelectionLockArgs = Cursor::from(args.to_vec()).into();
-    let omni_lock_hash = selection_args.omni_lock_hash();
-    let checkpoint_lock_hash = selection_args.checkpoint_lock_hash();
-
-    // count omni and checkpoint cells count
-    let mut omni_cells_count = 0;
-    let mut checkpoint_cells_count = 0;
-
-    // search omni and checkpoint cells via ckb functions
-    QueryIter::new(load_cell_lock_hash, Source::Input).for_each(|lock_hash| {
-        if &lock_hash == omni_lock_hash.as_slice() {
-            omni_cells_count += 1;
-        } else if &lock_hash == checkpoint_lock_hash.as_slice() {
-            checkpoint_cells_count += 1;
+    selection_args.verify()?;
+    let mut authorized_locks = vec![
+        (ROLE::OWNER, selection_args.omni_lock_hash()),
+        (ROLE::CHECKPOINT, selection_args.checkpoint_lock_hash()),
+    ];
+    let extra_authorized_locks = selection_args.authorized_locks();
+    for i in 0..extra_authorized_locks.len() {
+        let authorized_lock = extra_authorized_locks.get(i);
+        let role = get_role(authorized_lock.role())?;
+        if role == ROLE::RECOVERY {
+            return Err(Error::UnknownRole);
+        }
+        authorized_locks.push((role, authorized_lock.lock_hash()));
+    }
+    let recovery = selection_args.recovery();
+    if let Some(recovery) = &recovery {
+        authorized_locks.push((ROLE::RECOVERY, recovery.recovery_lock_hash()));
+    }
+
+    // search authorized cells via ckb functions, and collect their lock_hashes
+    let lock_hashes = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .filter(|lock_hash| {
+            authorized_locks
+                .iter()
+                .any(|(_, authorized_lock_hash)| lock_hash == authorized_lock_hash.as_slice())
+        })
+        .collect::<Vec<_>>();
+    let mut roles = Vec::new();
+    for (role, authorized_lock_hash) in &authorized_locks {
+        if lock_hashes
+            .iter()
+            .any(|lock_hash| lock_hash == authorized_lock_hash.as_slice())
+            && !roles.contains(role)
+        {
+            roles.push(*role);
         }
-    });
+    }
 
-    debug!(
-        "omni = {}, checkpoint = {}",
-        omni_cells_count, checkpoint_cells_count
-    );
+    debug!("authorized cells = {}", lock_hashes.len());
 
-    // sum of omni and checkpoint must be 1
-    if omni_cells_count + checkpoint_cells_count != 1 {
+    // the intended role named in witness must be authorized to a lock present in inputs, and an
+    // empty witness or a witness without input_type names no role
+    let intended_role = match load_witness_args(0, Source::GroupInput) {
+        Ok(witness) => match witness.input_type().to_opt() {
+            Some(value) => {
+                if value.len() != 1 {
+                    return Err(Error::BadWitnessInputType);
+                }
+                Some(get_role(value.raw_data()[0])?)
+            }
+            None => None,
+        },
+        Err(SysError::IndexOutOfBound) => None,
+        Err(SysError::Encoding) if load_witness(&mut [], 0, 0, Source::GroupInput) == Ok(0) => None,
+        Err(err) => return Err(err.into()),
+    };
+    if let Some(intended_role) = intended_role {
+        if !roles.contains(&intended_role) {
+            return Err(match intended_role {
+                ROLE::OWNER => Error::OwnerLockMissing,
+                ROLE::CHECKPOINT => Error::CheckpointLockMissing,
+                ROLE::EMERGENCY => Error::EmergencyLockMissing,
+                ROLE::RECOVERY => Error::RecoveryLockMissing,
+            });
+        }
+    }
+
+    // count of authorized cells must be 1
+    if lock_hashes.len() != 1 {
         return Err(Error::OmniCheckpointCountError);
     }
 
+    // a lock authorized to several roles must name its role in witness
+    let role = match intended_role {
+        Some(intended_role) => intended_role,
+        None if roles.len() == 1 => roles[0],
+        None => return Err(Error::BadWitnessInputType),
+    };
+
+    // recovery lock can only spend selection cell which has been alive for since_epochs, which
+    // is guaranteed by relative since in epoch of every selection cell
+    if let (ROLE::RECOVERY, Some(recovery)) = (role, &recovery) {
+        let since_epochs = bytes_to_u64(&recovery.since_epochs());
+        // since_epochs beyond the epoch number of since can never pass
+        if since_epochs > SINCE_EPOCH_NUMBER_MASK {
+            return Err(Error::BadRecoverySince);
+        }
+        QueryIter::new(load_input_since, Source::GroupInput)
+            .map(|since| {
+                if since & SINCE_RELATIVE_FLAG == 0
+                    || since & SINCE_METRIC_MASK != SINCE_METRIC_EPOCH
+                    || since & SINCE_EPOCH_NUMBER_MASK < since_epochs
+                {
+                    return Err(Error::BadRecoverySince);
+                }
+                Ok(())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    // only owner and recovery can destroy or move selection cell, otherwise it must be kept in
+    // exactly one output with the same type and data and at least the same capacity
+    if role != ROLE::OWNER && role != ROLE::RECOVERY {
+        let selection_lock_hash = load_script_hash()?;
+        let input_capacity = QueryIter::new(load_cell_capacity, Source::GroupInput)
+            .try_fold(0u64, |total, capacity| total.checked_add(capacity))
+            .ok_or(Error::SelectionCapacityMismatch)?;
+        let output_indexes = QueryIter::new(load_cell_lock_hash, Source::Output)
+            .enumerate()
+            .filter(|(_, lock_hash)| lock_hash == &selection_lock_hash)
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if output_indexes.len() != 1 {
+            return Err(Error::SelectionCellNotKept);
+        }
+        let output_type_hash = load_cell_type_hash(output_indexes[0], Source::Output)?;
+        let output_data = load_cell_data(output_indexes[0], Source::Output)?;
+        if QueryIter::new(load_cell_type_hash, Source::GroupInput)
+            .any(|type_hash| type_hash != output_type_hash)
+            || QueryIter::new(load_cell_data, Source::GroupInput).any(|data| data != output_data)
+        {
+            return Err(Error::SelectionCellNotKept);
+        }
+        if load_cell_capacity(output_indexes[0], Source::Output)? < input_capacity {
+            return Err(Error::SelectionCapacityMismatch);
+        }
+    }
+
     Ok(())
 }
@@ -1,6 +1,8 @@
 use ckb_std::error::SysError;
+use protocol::MoleculeError;
 
 /// Error
+#[allow(clippy::enum_variant_names)]
 #[repr(i8)]
 pub enum Error {
     IndexOutOfBound = 1,
@@ -9,6 +11,15 @@ pub enum Error {
     Encoding,
     // Add customized errors here...
     OmniCheckpointCountError,
+    UnknownRole,
+    BadWitnessInputType,
+    OwnerLockMissing,
+    CheckpointLockMissing,
+    EmergencyLockMissing,
+    SelectionCellNotKept,
+    SelectionCapacityMismatch,
+    RecoveryLockMissing,
+    BadRecoverySince,
 }
 
 impl From<SysError> for Error {
@@ -23,3 +34,9 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl From<MoleculeError> for Error {
+    fn from(_: MoleculeError) -> Self {
+        Self::Encoding
+    }
+}
@@ -1,9 +1,13 @@
 // Import from `core` instead of from `std` since we are in no-std mode
-use core::result::Result;
+use core::{cmp::Ordering, convert::TryFrom, result::Result};
 
 // Import heap related library from `alloc`
 // https://doc.rust-lang.org/alloc/index.html
-use alloc::{collections::BTreeSet, vec, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec,
+    vec::Vec,
+};
 
 // Import CKB syscalls and structures
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
@@ -12,46 +16,56 @@ use ckb_std::{
     ckb_types::{bytes::Bytes, prelude::*},
     debug,
     high_level::{
-        load_cell_data, load_cell_lock, load_cell_type_hash, load_script, load_witness_args,
-        QueryIter,
+        load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type_hash, load_script,
+        load_witness_args, QueryIter,
     },
 };
 
 use crate::error::Error;
 use protocol::{
-    axon::{self, StakeInfo},
-    read_at, Cursor,
+    axon::{self, DelegateInfo, StakeInfo},
+    bytes_to_u128, bytes_to_u32, bytes_to_u64, mode,
+    slash::{self, SlashProof},
+    sudt, withdrawal, Cursor,
 };
 
+#[allow(clippy::upper_case_acronyms)]
 enum FILTER {
     APPLIED,
     APPLYING,
     NOTAPPLY,
 }
 
+#[allow(clippy::upper_case_acronyms)]
 enum MODE {
     UPDATE,
     BURN,
     ADMIN,
     COMPANION,
+    UNSTAKE,
+    SLASH,
+    DELEGATE,
 }
 
 fn get_stake_data_by_type_hash(
-    cell_type_hash: &[u8; 32],
+    cell_type_hash: &[u8],
     source: Source,
 ) -> Result<axon::StakeLockCellData, Error> {
     let mut stake_data = None;
     QueryIter::new(load_cell_type_hash, source)
         .enumerate()
         .map(|(i, type_hash)| {
-            if &type_hash.unwrap_or([0u8; 32]) == cell_type_hash {
-                assert!(stake_data.is_none());
+            if type_hash.unwrap_or([0u8; 32])[..] == cell_type_hash[..] {
+                if stake_data.is_some() {
+                    return Err(Error::StakeDataError);
+                }
                 stake_data = {
                     let data = load_cell_data(i, source);
-                    if let Err(_) = data {
+                    if data.is_err() {
                         return Err(Error::StakeDataError);
                     }
                     let stake_data: axon::StakeLockCellData = Cursor::from(data.unwrap()).into();
+                    stake_data.verify()?;
                     Some(stake_data)
                 };
             }
@@ -65,21 +79,24 @@ fn get_stake_data_by_type_hash(
 }
 
 fn get_checkpoint_from_celldeps(
-    checkpoint_type_hash: &Vec<u8>,
+    checkpoint_type_hash: &[u8],
 ) -> Result<axon::CheckpointLockCellData, Error> {
     let mut checkpoint_data = None;
     QueryIter::new(load_cell_type_hash, Source::CellDep)
         .enumerate()
         .map(|(i, type_hash)| {
             if type_hash.unwrap_or([0u8; 32])[..] == checkpoint_type_hash[..] {
-                assert!(checkpoint_data.is_none());
+                if checkpoint_data.is_some() {
+                    return Err(Error::CheckpointDataError);
+                }
                 checkpoint_data = {
                     let data = load_cell_data(i, Source::CellDep);
-                    if let Err(_) = data {
+                    if data.is_err() {
                         return Err(Error::CheckpointDataError);
                     }
                     let checkpoint_data: axon::CheckpointLockCellData =
                         Cursor::from(data.unwrap()).into();
+                    checkpoint_data.verify()?;
                     Some(checkpoint_data)
                 };
             }
@@ -92,16 +109,72 @@ fn get_checkpoint_from_celldeps(
     Ok(checkpoint_data.unwrap())
 }
 
-fn bytes_to_u64(bytes: &Vec<u8>) -> u64 {
-    let mut array: [u8; 8] = [0u8; 8];
-    array.copy_from_slice(bytes.as_slice());
-    u64::from_le_bytes(array)
+// AT held by stake lock is the sum of stake_amount and delegated amount from the latest
+// stake_info and delegate_info of each node and delegator, including the ones which have not
+// taken effect yet, since AT is deposited or released as soon as they are appended
+fn get_total_locked_amount(stake_data: &axon::StakeLockCellData) -> Result<u128, Error> {
+    let total_stake_amount =
+        protocol::stake::get_stake_infos_by_era(&stake_data.stake_infos(), u64::MAX)?
+            .iter()
+            .try_fold(0u128, |total, stake_info| {
+                total.checked_add(bytes_to_u128(&stake_info.stake_amount()))
+            })
+            .ok_or(Error::ATAmountOverflow)?;
+    protocol::stake::get_delegate_infos_by_era(&stake_data.delegate_infos(), u64::MAX)?
+        .iter()
+        .try_fold(total_stake_amount, |total, delegate_info| {
+            total.checked_add(bytes_to_u128(&delegate_info.amount()))
+        })
+        .ok_or(Error::ATAmountOverflow)
+}
+
+// stake_amount of the node of `stake_info` which is in effect in the inauguration_era of
+// `stake_info` before it is appended, which is what the appended stake_info deposits or
+// releases against
+fn get_replaced_stake_amount(
+    stake_infos: &axon::StakeInfoVec,
+    stake_info: &StakeInfo,
+) -> Result<u128, Error> {
+    let identity = Vec::<u8>::try_from(stake_info.identity().cursor)?;
+    let era = bytes_to_u64(&stake_info.inauguration_era());
+    for stake_info in protocol::stake::get_stake_infos_by_era(stake_infos, era)? {
+        if Vec::<u8>::try_from(stake_info.identity().cursor.clone())? == identity {
+            return Ok(bytes_to_u128(&stake_info.stake_amount()));
+        }
+    }
+    Ok(0)
+}
+
+// amount of the delegator of `delegate_info` to its validator which is in effect in the
+// inauguration_era of `delegate_info` before it is appended, which is what the appended
+// delegate_info deposits or releases against
+fn get_replaced_delegate_amount(
+    delegate_infos: &axon::DelegateInfoVec,
+    delegate_info: &DelegateInfo,
+) -> Result<u128, Error> {
+    let delegator_identity = Vec::<u8>::try_from(delegate_info.delegator_identity().cursor)?;
+    let validator_identity = Vec::<u8>::try_from(delegate_info.validator_identity().cursor)?;
+    let era = bytes_to_u64(&delegate_info.inauguration_era());
+    for delegate_info in protocol::stake::get_delegate_infos_by_era(delegate_infos, era)? {
+        if Vec::<u8>::try_from(delegate_info.delegator_identity().cursor)? == delegator_identity
+            && Vec::<u8>::try_from(delegate_info.validator_identity().cursor)? == validator_identity
+        {
+            return Ok(bytes_to_u128(&delegate_info.amount()));
+        }
+    }
+    Ok(0)
 }
 
-fn bytes_to_u32(bytes: &Vec<u8>) -> u32 {
-    let mut array: [u8; 4] = [0u8; 4];
-    array.copy_from_slice(bytes.as_slice());
-    u32::from_le_bytes(array)
+// check AT held by stake lock in outputs equals to the total stake and delegation of output
+// stake_data
+fn check_total_locked_amount(
+    output_stake_data: &axon::StakeLockCellData,
+    output_at_amount: u128,
+) -> Result<(), Error> {
+    if get_total_locked_amount(output_stake_data)? != output_at_amount {
+        return Err(Error::StakeAmountMismatch);
+    }
+    Ok(())
 }
 
 fn filter_stakeinfos_by_era(
@@ -109,35 +182,386 @@ fn filter_stakeinfos_by_era(
     stake_infos: &axon::StakeInfoVec,
     filter_type: FILTER,
 ) -> Result<BTreeSet<Vec<u8>>, Error> {
+    let applying_era = era.checked_add(1).ok_or(Error::CheckpointDataError)?;
     let mut filtered_stake_infos = BTreeSet::new();
-    match filter_type {
-        FILTER::APPLIED => {}
-        FILTER::APPLYING => {}
-        FILTER::NOTAPPLY => {
-            for i in 0..stake_infos.len() {
-                let stake_info = stake_infos.get(i);
-                if bytes_to_u64(&stake_info.inauguration_era()) > era + 1 {
-                    let mut bytes = vec![0u8; stake_info.cursor.size];
-                    read_at(&stake_info.cursor, bytes.as_mut_slice());
-                    if !filtered_stake_infos.insert(bytes.to_vec()) {
-                        return Err(Error::StakeDataEmpty);
-                    }
-                }
-            }
+    for i in 0..stake_infos.len() {
+        let stake_info = stake_infos.get(i);
+        let inauguration_era = bytes_to_u64(&stake_info.inauguration_era());
+        let filtered = match filter_type {
+            FILTER::APPLIED => inauguration_era <= era,
+            FILTER::APPLYING => inauguration_era == applying_era,
+            FILTER::NOTAPPLY => inauguration_era > applying_era,
+        };
+        if filtered && !filtered_stake_infos.insert(Vec::<u8>::try_from(stake_info.cursor)?) {
+            return Err(Error::StakeDataEmpty);
         }
     }
     Ok(filtered_stake_infos)
 }
 
-fn stakeinfos_into_set(stake_infos: &axon::StakeInfoVec) -> BTreeSet<Vec<u8>> {
-    let mut btree_set = BTreeSet::new();
+// search node identities of companion cells, which are locked by stake lock with the same
+// type_id_hash and a node identity, from inputs, and map each of them to the index of its first
+// companion cell, where the witness of its lock group is placed
+fn get_companion_identities(type_id_hash: &[u8]) -> Result<BTreeMap<Vec<u8>, usize>, Error> {
+    let script = load_script()?;
+    let mut node_identities = BTreeMap::new();
+    for (i, lock) in QueryIter::new(load_cell_lock, Source::Input).enumerate() {
+        if lock.code_hash().as_slice() != script.code_hash().as_slice()
+            || lock.hash_type() != script.hash_type()
+        {
+            continue;
+        }
+        let args: Bytes = lock.args().unpack();
+        let stake_args: axon::StakeLockArgs = Cursor::from(args.to_vec()).into();
+        stake_args.verify()?;
+        if stake_args.type_id_hash() != type_id_hash {
+            continue;
+        }
+        if let Some(node_identity) = stake_args.node_identity() {
+            node_identities
+                .entry(Vec::<u8>::try_from(node_identity.cursor)?)
+                .or_insert(i);
+        }
+    }
+    Ok(node_identities)
+}
+
+fn is_stake_params_unchanged(
+    input_stake_data: &axon::StakeLockCellData,
+    output_stake_data: &axon::StakeLockCellData,
+) -> bool {
+    input_stake_data.version() == output_stake_data.version()
+        && input_stake_data.checkpoint_type_hash() == output_stake_data.checkpoint_type_hash()
+        && input_stake_data.sudt_type_hash() == output_stake_data.sudt_type_hash()
+        && input_stake_data.quorum_size() == output_stake_data.quorum_size()
+        && input_stake_data.unbonding_eras() == output_stake_data.unbonding_eras()
+        && input_stake_data.slash_rate() == output_stake_data.slash_rate()
+}
+
+// check stake_data between input and output, which must append exactly one not_applied
+// stake_info, and return the appended stake_info
+fn get_appended_stake_info(
+    input_stake_data: &axon::StakeLockCellData,
+    output_stake_data: &axon::StakeLockCellData,
+    era: u64,
+) -> Result<StakeInfo, Error> {
+    if !is_stake_params_unchanged(input_stake_data, output_stake_data)
+        || Vec::<u8>::try_from(input_stake_data.delegate_infos().cursor)?
+            != Vec::<u8>::try_from(output_stake_data.delegate_infos().cursor)?
+    {
+        return Err(Error::UpdateModeError);
+    }
+
+    let input_stake_infos = input_stake_data.stake_infos();
+    let output_stake_infos = output_stake_data.stake_infos();
+    if output_stake_infos.len() != input_stake_infos.len() + 1 {
+        return Err(Error::NotApplyStakeInfoError);
+    }
+    let node_stake_info = output_stake_infos.get(output_stake_infos.len() - 1);
+    let node_identity = Vec::<u8>::try_from(node_stake_info.identity().cursor)?;
+    if node_stake_info.commission_rate() > 100 {
+        return Err(Error::NotApplyStakeInfoError);
+    }
+
+    // check dumplicate stake_info of the same node and era in stake_infos from input, and the
+    // appended stake_info is scheduled after every stake_info of the same node, so the latest
+    // stake_info of each node is always the last appended one
+    let inauguration_era = bytes_to_u64(&node_stake_info.inauguration_era());
+    for i in 0..input_stake_infos.len() {
+        let stake_info = input_stake_infos.get(i);
+        let identity = Vec::<u8>::try_from(stake_info.identity().cursor)?;
+        if identity != node_identity {
+            continue;
+        }
+        let scheduled_era = bytes_to_u64(&stake_info.inauguration_era());
+        if scheduled_era == inauguration_era {
+            return Err(Error::DumplicateInputStakeInfo);
+        }
+        if scheduled_era > inauguration_era {
+            return Err(Error::BadInaugurationEra);
+        }
+    }
+
+    // check applied and applying stake_infos are unchanged, and the appended stake_info is the
+    // only difference between input and output not_applied stake_infos
+    if filter_stakeinfos_by_era(era, &input_stake_infos, FILTER::APPLIED)?
+        != filter_stakeinfos_by_era(era, &output_stake_infos, FILTER::APPLIED)?
+        || filter_stakeinfos_by_era(era, &input_stake_infos, FILTER::APPLYING)?
+            != filter_stakeinfos_by_era(era, &output_stake_infos, FILTER::APPLYING)?
+    {
+        return Err(Error::UpdateModeError);
+    }
+    let mut input_notapply_stake_infos =
+        filter_stakeinfos_by_era(era, &input_stake_infos, FILTER::NOTAPPLY)?;
+    let output_notapply_stake_infos =
+        filter_stakeinfos_by_era(era, &output_stake_infos, FILTER::NOTAPPLY)?;
+    let node_stake_info_bytes = Vec::<u8>::try_from(node_stake_info.cursor.clone())?;
+    if !input_notapply_stake_infos.insert(node_stake_info_bytes)
+        || input_notapply_stake_infos != output_notapply_stake_infos
+    {
+        return Err(Error::NotApplyStakeInfoError);
+    }
+    Ok(node_stake_info)
+}
+
+// check stake_data between input and output in slash mode, which must drop every stake_info of
+// the slashed node scheduled from `slash_inauguration_era`, and append exactly one stake_info of
+// it taking effect in `slash_inauguration_era`, and return the appended stake_info
+fn get_slashing_stake_info(
+    input_stake_data: &axon::StakeLockCellData,
+    output_stake_data: &axon::StakeLockCellData,
+    slash_inauguration_era: u64,
+) -> Result<StakeInfo, Error> {
+    if !is_stake_params_unchanged(input_stake_data, output_stake_data)
+        || Vec::<u8>::try_from(input_stake_data.delegate_infos().cursor)?
+            != Vec::<u8>::try_from(output_stake_data.delegate_infos().cursor)?
+    {
+        return Err(Error::UpdateModeError);
+    }
+
+    let input_stake_infos = input_stake_data.stake_infos();
+    let output_stake_infos = output_stake_data.stake_infos();
+    if output_stake_infos.len() == 0 {
+        return Err(Error::NotApplyStakeInfoError);
+    }
+    let node_stake_info = output_stake_infos.get(output_stake_infos.len() - 1);
+    let node_identity = Vec::<u8>::try_from(node_stake_info.identity().cursor)?;
+    if bytes_to_u64(&node_stake_info.inauguration_era()) != slash_inauguration_era {
+        return Err(Error::BadInaugurationEra);
+    }
+
+    // pending stake_infos of the slashed node are superseded by the appended one, so the node can
+    // not escape from the slash by scheduling a stake_info far in the future, while the rest of
+    // stake_infos are kept in order
+    let mut kept_stake_infos = Vec::new();
+    for i in 0..input_stake_infos.len() {
+        let stake_info = input_stake_infos.get(i);
+        if Vec::<u8>::try_from(stake_info.identity().cursor.clone())? == node_identity
+            && bytes_to_u64(&stake_info.inauguration_era()) >= slash_inauguration_era
+        {
+            continue;
+        }
+        kept_stake_infos.push(Vec::<u8>::try_from(stake_info.cursor)?);
+    }
+    if kept_stake_infos.len() != output_stake_infos.len() - 1 {
+        return Err(Error::NotApplyStakeInfoError);
+    }
+    for (i, stake_info) in kept_stake_infos.into_iter().enumerate() {
+        if Vec::<u8>::try_from(output_stake_infos.get(i).cursor)? != stake_info {
+            return Err(Error::NotApplyStakeInfoError);
+        }
+    }
+    Ok(node_stake_info)
+}
+
+// check stake_data between input and output, which must append exactly one delegate_info, and
+// return the appended delegate_info
+fn get_appended_delegate_info(
+    input_stake_data: &axon::StakeLockCellData,
+    output_stake_data: &axon::StakeLockCellData,
+) -> Result<DelegateInfo, Error> {
+    if !is_stake_params_unchanged(input_stake_data, output_stake_data)
+        || Vec::<u8>::try_from(input_stake_data.stake_infos().cursor)?
+            != Vec::<u8>::try_from(output_stake_data.stake_infos().cursor)?
+    {
+        return Err(Error::UpdateModeError);
+    }
+
+    let input_delegate_infos = input_stake_data.delegate_infos();
+    let output_delegate_infos = output_stake_data.delegate_infos();
+    if output_delegate_infos.len() != input_delegate_infos.len() + 1 {
+        return Err(Error::DelegateInfoError);
+    }
+    let delegate_info = output_delegate_infos.get(output_delegate_infos.len() - 1);
+    let delegator_identity = Vec::<u8>::try_from(delegate_info.delegator_identity().cursor)?;
+    let validator_identity = Vec::<u8>::try_from(delegate_info.validator_identity().cursor)?;
+    if delegator_identity == validator_identity {
+        return Err(Error::DelegateInfoError);
+    }
+
+    // check delegate_infos from input are unchanged, and the appended delegate_info is scheduled
+    // after every delegate_info of the same delegator and validator, so the latest delegate_info
+    // of each pair is always the last appended one
+    let inauguration_era = bytes_to_u64(&delegate_info.inauguration_era());
+    for i in 0..input_delegate_infos.len() {
+        let input_delegate_info = input_delegate_infos.get(i);
+        if Vec::<u8>::try_from(input_delegate_info.cursor.clone())?
+            != Vec::<u8>::try_from(output_delegate_infos.get(i).cursor)?
+        {
+            return Err(Error::DelegateInfoError);
+        }
+        if Vec::<u8>::try_from(input_delegate_info.delegator_identity().cursor)?
+            != delegator_identity
+            || Vec::<u8>::try_from(input_delegate_info.validator_identity().cursor)?
+                != validator_identity
+        {
+            continue;
+        }
+        let scheduled_era = bytes_to_u64(&input_delegate_info.inauguration_era());
+        if scheduled_era == inauguration_era {
+            return Err(Error::DumplicateInputStakeInfo);
+        }
+        if scheduled_era > inauguration_era {
+            return Err(Error::BadInaugurationEra);
+        }
+    }
+    Ok(delegate_info)
+}
+
+// check the appended stake_info is authorized by the companion cell of its node, and a
+// bls_pub_key which the node has never registered is proved to be possessed by the node, so it
+// can not be a rogue key cancelling others in aggregated signatures
+fn check_companion_identity(
+    type_id_hash: &[u8],
+    input_stake_infos: &axon::StakeInfoVec,
+    node_stake_info: &StakeInfo,
+) -> Result<(), Error> {
+    let node_identity = Vec::<u8>::try_from(node_stake_info.identity().cursor)?;
+    let companion_index = *get_companion_identities(type_id_hash)?
+        .get(&node_identity)
+        .ok_or(Error::CompanionCellEmpty)?;
+    let bls_pub_key = node_stake_info.bls_pub_key();
+    for i in 0..input_stake_infos.len() {
+        let stake_info = input_stake_infos.get(i);
+        if Vec::<u8>::try_from(stake_info.identity().cursor)? == node_identity
+            && stake_info.bls_pub_key() == bls_pub_key
+        {
+            return Ok(());
+        }
+    }
+
+    // the proof of possession follows the mode byte in input_type of the companion witness
+    let input_type = load_witness_args(companion_index, Source::Input)?
+        .input_type()
+        .to_opt()
+        .ok_or(Error::BadProofOfPossession)?
+        .raw_data();
+    if input_type.len() < 2 || !bls::verify_proof_of_possession(&bls_pub_key, &input_type[1..]) {
+        return Err(Error::BadProofOfPossession);
+    }
+    Ok(())
+}
+
+// check both votes are signed by `bls_pub_key` for two different blocks of the same height
+fn verify_slash_proof(slash_proof: &SlashProof, bls_pub_key: &[u8]) -> bool {
+    let [vote, conflicting_vote] = slash_proof.votes();
+    let bls_pub_keys = vec![bls_pub_key.to_vec()];
+    let block_hash = keccak::keccak256(vote.header());
+    let conflicting_block_hash = keccak::keccak256(conflicting_vote.header());
+    vote.block_number() == conflicting_vote.block_number()
+        && block_hash != conflicting_block_hash
+        && bls::verify_aggregate_signature(&bls_pub_keys, &block_hash, vote.signature())
+        && bls::verify_aggregate_signature(
+            &bls_pub_keys,
+            &conflicting_block_hash,
+            conflicting_vote.signature(),
+        )
+}
+
+// AT released from the stake of node by stake_infos which have not taken effect in `era`, which
+// is still unbonding in Withdrawal AT cells of node, while AT released before the latest slash of
+// node has been slashed already
+fn get_unbonding_stake_amount(
+    stake_infos: &axon::StakeInfoVec,
+    node_identity: &[u8],
+    era: u64,
+) -> Result<u128, Error> {
+    let mut stake_amount = 0u128;
+    let mut unbonding_amount = 0u128;
     for i in 0..stake_infos.len() {
         let stake_info = stake_infos.get(i);
-        let mut bytes = vec![0u8; stake_info.cursor.size];
-        read_at(&stake_info.cursor, bytes.as_mut_slice());
-        btree_set.insert(bytes.to_vec());
+        if Vec::<u8>::try_from(stake_info.identity().cursor.clone())? != node_identity {
+            continue;
+        }
+        let amount = bytes_to_u128(&stake_info.stake_amount());
+        if stake_info.bls_pub_key().iter().all(|byte| *byte == 0) {
+            unbonding_amount = 0;
+        } else if amount < stake_amount && bytes_to_u64(&stake_info.inauguration_era()) > era {
+            unbonding_amount = unbonding_amount
+                .checked_add(stake_amount - amount)
+                .ok_or(Error::ATAmountOverflow)?;
+        }
+        stake_amount = amount;
     }
-    btree_set
+    Ok(unbonding_amount)
+}
+
+// check `amount` of AT is released to the Withdrawal AT cell of node, which can not be unlocked
+// until unlock_period after the first period of `inauguration_era` when the release takes effect,
+// while Withdrawal AT cells of node in inputs lose slash_rate of AT, and return the slashed AT of
+// them
+//
+// Withdrawal AT cells in inputs which have not matured in the period of `checkpoint` must hold at
+// least `unbonding_amount` of AT, so AT under unbonding can not be kept out of a slash
+#[allow(clippy::too_many_arguments)]
+fn check_released_withdrawal(
+    admin_identity: &[u8],
+    node_identity: &[u8],
+    stake_data: &axon::StakeLockCellData,
+    checkpoint: &axon::CheckpointLockCellData,
+    inauguration_era: u64,
+    amount: u128,
+    slash_rate: u8,
+    unbonding_amount: u128,
+) -> Result<u128, Error> {
+    let sudt_type_hash = stake_data.sudt_type_hash();
+    let withdrawal_expiry = checkpoint
+        .withdrawal_expiry()
+        .map(|expiry| Vec::<u8>::try_from(expiry.cursor))
+        .transpose()?;
+    let withdrawal_lock = withdrawal::lock_script(
+        &checkpoint.withdrawal_lock_code_hash(),
+        &withdrawal::lock_args(
+            admin_identity,
+            &stake_data.checkpoint_type_hash(),
+            Some(node_identity),
+            withdrawal_expiry.as_deref(),
+        ),
+    );
+    let input_withdrawal_cells =
+        withdrawal::get_withdrawal_cells(&withdrawal_lock, &sudt_type_hash, Source::Input)?;
+    let output_withdrawal_cells =
+        withdrawal::get_withdrawal_cells(&withdrawal_lock, &sudt_type_hash, Source::Output)?;
+    let era_period = bytes_to_u32(&checkpoint.era_period()) as u64;
+    if era_period == 0 {
+        return Err(Error::CheckpointDataError);
+    }
+    let withdrawal_period = inauguration_era
+        .checked_mul(era_period)
+        .and_then(|period| period.checked_add(bytes_to_u32(&checkpoint.unlock_period()) as u64))
+        .ok_or(Error::CheckpointDataError)?;
+    if output_withdrawal_cells
+        .iter()
+        .any(|(_, period)| period < &withdrawal_period)
+    {
+        return Err(Error::BadWithdrawalPeriod);
+    }
+    let input_withdrawal_amount = input_withdrawal_cells
+        .iter()
+        .try_fold(0u128, |total, (amount, _)| total.checked_add(*amount))
+        .ok_or(Error::ATAmountOverflow)?;
+    let checkpoint_period = bytes_to_u64(&checkpoint.period());
+    let unbonding_withdrawal_amount = input_withdrawal_cells
+        .iter()
+        .filter(|(_, period)| period > &checkpoint_period)
+        .try_fold(0u128, |total, (amount, _)| total.checked_add(*amount))
+        .ok_or(Error::ATAmountOverflow)?;
+    if unbonding_withdrawal_amount < unbonding_amount {
+        return Err(Error::UnbondingATNotSlashed);
+    }
+    let output_withdrawal_amount = output_withdrawal_cells
+        .iter()
+        .try_fold(0u128, |total, (amount, _)| total.checked_add(*amount))
+        .ok_or(Error::ATAmountOverflow)?;
+    let slashed_withdrawal_amount = slash::get_slashed_amount(input_withdrawal_amount, slash_rate)
+        .ok_or(Error::StakeDataError)?;
+    let remained_withdrawal_amount = input_withdrawal_amount
+        .checked_sub(slashed_withdrawal_amount)
+        .ok_or(Error::StakeDataError)?;
+    if Some(output_withdrawal_amount) != remained_withdrawal_amount.checked_add(amount) {
+        return Err(Error::WithdrawalAmountMismatch);
+    }
+    Ok(slashed_withdrawal_amount)
 }
 
 pub fn main() -> Result<(), Error> {
@@ -146,28 +570,36 @@ pub fn main() -> Result<(), Error> {
 
     // extract stake_args
     let stake_args: axon::StakeLockArgs = Cursor::from(args.to_vec()).into();
+    stake_args.verify()?;
     let admin_identity = stake_args.admin_identity();
     let type_id_hash = stake_args.type_id_hash();
     let node_identity = stake_args.node_identity();
 
     // identify contract mode by witness
+    let mut witness_lock = None;
     let mode = match load_witness_args(0, Source::GroupInput) {
         Ok(witness) => {
-            let value = witness.input_type().to_opt();
-            if value.is_none() || value.as_ref().unwrap().len() != 1 {
+            witness_lock = witness.lock().to_opt();
+            let input_type = match witness.input_type().to_opt() {
+                Some(value) if !value.is_empty() => value.raw_data(),
+                _ => return Err(Error::BadWitnessInputType),
+            };
+            // only the companion witness carries more than the mode byte, which is the proof of
+            // possession of a new bls_pub_key
+            if input_type.len() != 1 && (node_identity.is_none() || input_type[0] == mode::BURN) {
                 return Err(Error::BadWitnessInputType);
             }
-            if value.unwrap().raw_data().to_vec().first().unwrap() == &0 {
-                if node_identity.is_none() {
-                    MODE::ADMIN
-                } else {
-                    MODE::BURN
+            match (input_type[0], node_identity.is_some()) {
+                (mode::ADMIN, false) => MODE::ADMIN,
+                (mode::BURN, true) => MODE::BURN,
+                (mode::UNSTAKE, false) => MODE::UNSTAKE,
+                (mode::SLASH, false) => MODE::SLASH,
+                (mode::DELEGATE, false) => MODE::DELEGATE,
+                (mode::UNSTAKE, true) | (mode::SLASH, true) | (mode::DELEGATE, true) => {
+                    return Err(Error::UnknownMode)
                 }
-            } else {
-                if node_identity.is_none() {
-                    return Err(Error::UnknownMode);
-                }
-                MODE::COMPANION
+                (_, true) => MODE::COMPANION,
+                (_, false) => return Err(Error::UnknownMode),
             }
         }
         Err(_) => MODE::UPDATE,
@@ -189,7 +621,8 @@ pub fn main() -> Result<(), Error> {
             if !secp256k1::verify_signature(&mut admin_identity.content()) {
                 return Err(Error::SignatureMismatch);
             }
-            let input_stake_data = get_stake_data_by_type_hash(&typeid_or_at_type_hash, Source::Input)?;
+            let input_stake_data =
+                get_stake_data_by_type_hash(&typeid_or_at_type_hash, Source::Input)?;
             let output_stake_data =
                 get_stake_data_by_type_hash(&typeid_or_at_type_hash, Source::Output)?;
             if input_stake_data.version() != output_stake_data.version()
@@ -197,9 +630,18 @@ pub fn main() -> Result<(), Error> {
                     != output_stake_data.checkpoint_type_hash()
                 || input_stake_data.sudt_type_hash() != output_stake_data.sudt_type_hash()
                 || output_stake_data.quorum_size() > 160
+                || output_stake_data.slash_rate() > slash::MAX_SLASH_RATE
             {
                 return Err(Error::AdminModeError);
             }
+
+            // stake_infos and delegate_infos rewritten by admin must still be backed by AT held
+            // by stake lock
+            let sudt_type_hash = output_stake_data.sudt_type_hash();
+            let stake_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let output_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Output)?;
+            check_total_locked_amount(&output_stake_data, output_at_amount)?;
         }
         MODE::BURN => {
             debug!("burn mode");
@@ -235,46 +677,316 @@ pub fn main() -> Result<(), Error> {
         }
         MODE::UPDATE => {
             debug!("update mode");
-            // check stake_data between input and output
-            // let input_stake_data = get_stake_data_by_type_hash(&typeid_or_at_type_hash, Source::Input)?;
-            // let output_stake_data = get_stake_data_by_type_hash(&typeid_or_at_type_hash, Source::Output)?;
-            // if input_stake_data.version() != output_stake_data.version()
-            //     || input_stake_data.checkpoint_type_hash() != output_stake_data.checkpoint_type_hash()
-            //     || input_stake_data.sudt_type_hash() != output_stake_data.sudt_type_hash()
-            //     || input_stake_data.quorum_size() != output_stake_data.quorum_size() {
-            //     return Err(Error::UpdateModeError);
-            // }
-
-            // // get checkpoint data from celldeps
-            // let checkpoint = get_checkpoint_from_celldeps(&input_stake_data.checkpoint_type_hash())?;
-            // let era = bytes_to_u64(&checkpoint.era());
-            // let period = bytes_to_u64(&checkpoint.period());
-            // let unlock_period = bytes_to_u32(&checkpoint.unlock_period());
-
-            // // get different stake_info between input not_applied stake_infos and output not_applied stake_infos
-            // let input_stake_infos = input_stake_data.stake_infos();
-            // let output_stake_infos = output_stake_data.stake_infos();
-            // let input_notapply_stake_infos = filter_stakeinfos_by_era(era, &input_stake_infos, FILTER::NOTAPPLY)?;
-            // let output_notapply_stake_infos = filter_stakeinfos_by_era(era, &output_stake_infos, FILTER::NOTAPPLY)?;
-            // let node_stake_info = {
-            //     if output_notapply_stake_infos.len() != input_notapply_stake_infos.len() + 1 {
-            //         return Err(Error::NotApplyStakeInfoError);
-            //     }
-            //     let diff_stake_infos = output_notapply_stake_infos
-            // 		.symmetric_difference(&input_notapply_stake_infos)
-            // 		.cloned()
-            // 		.collect::<Vec<u8>>();
-            // 	if diff_stake_infos.len() != 1 {
-            //         return Err(Error::NotApplyStakeInfoError);
-            // 	}
-            //     diff_stake_infos.first().unwrap()
-            // };
-
-            // // check dumplicate stake_info in stake_infos from input
-            // let mut stake_infos = stakeinfos_into_set(&input_stake_infos);
-            // if !stake_infos.insert(node_stake_info) {
-            // 	return Err(Error::DumplicateInputStakeInfo);
-            // }
+            // AT cells under stake lock are also in this group, so stake cell is searched by
+            // type_id_hash from args
+            let input_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Input)?;
+            let output_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Output)?;
+
+            // get checkpoint data from celldeps
+            let checkpoint =
+                get_checkpoint_from_celldeps(&input_stake_data.checkpoint_type_hash())?;
+            let era = bytes_to_u64(&checkpoint.era());
+
+            // appended stake_info takes effect from era + 2
+            let node_stake_info =
+                get_appended_stake_info(&input_stake_data, &output_stake_data, era)?;
+            check_companion_identity(
+                &type_id_hash,
+                &input_stake_data.stake_infos(),
+                &node_stake_info,
+            )?;
+            let inauguration_era = era.checked_add(2).ok_or(Error::CheckpointDataError)?;
+            if bytes_to_u64(&node_stake_info.inauguration_era()) != inauguration_era {
+                return Err(Error::BadInaugurationEra);
+            }
+
+            // check increased stake is deposited into AT cells under stake lock, so the total
+            // stake always equals to AT held by stake lock
+            let sudt_type_hash = input_stake_data.sudt_type_hash();
+            let stake_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let input_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Input)?;
+            let output_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Output)?;
+            let deposit_stake_amount = bytes_to_u128(&node_stake_info.stake_amount())
+                .checked_sub(get_replaced_stake_amount(
+                    &input_stake_data.stake_infos(),
+                    &node_stake_info,
+                )?)
+                .ok_or(Error::StakeAmountMismatch)?;
+            if output_at_amount.checked_sub(input_at_amount) != Some(deposit_stake_amount) {
+                return Err(Error::StakeAmountMismatch);
+            }
+            check_total_locked_amount(&output_stake_data, output_at_amount)?;
+        }
+        MODE::UNSTAKE => {
+            debug!("unstake mode");
+            let input_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Input)?;
+            let output_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Output)?;
+
+            // get checkpoint data from celldeps
+            let checkpoint =
+                get_checkpoint_from_celldeps(&input_stake_data.checkpoint_type_hash())?;
+            let era = bytes_to_u64(&checkpoint.era());
+
+            // appended stake_info takes effect no earlier than era + unbonding_eras
+            let node_stake_info =
+                get_appended_stake_info(&input_stake_data, &output_stake_data, era)?;
+            check_companion_identity(
+                &type_id_hash,
+                &input_stake_data.stake_infos(),
+                &node_stake_info,
+            )?;
+            let unbonding_era = era
+                .checked_add(bytes_to_u64(&input_stake_data.unbonding_eras()))
+                .ok_or(Error::StakeDataError)?;
+            if bytes_to_u64(&node_stake_info.inauguration_era()) < unbonding_era {
+                return Err(Error::BadInaugurationEra);
+            }
+
+            // check decreased stake is released from AT cells under stake lock
+            let sudt_type_hash = input_stake_data.sudt_type_hash();
+            let stake_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let input_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Input)?;
+            let output_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Output)?;
+            let release_stake_amount =
+                get_replaced_stake_amount(&input_stake_data.stake_infos(), &node_stake_info)?
+                    .checked_sub(bytes_to_u128(&node_stake_info.stake_amount()))
+                    .ok_or(Error::StakeAmountMismatch)?;
+            if release_stake_amount == 0
+                || input_at_amount.checked_sub(output_at_amount) != Some(release_stake_amount)
+            {
+                return Err(Error::StakeAmountMismatch);
+            }
+            check_total_locked_amount(&output_stake_data, output_at_amount)?;
+
+            // check released AT goes to the Withdrawal AT cell of node
+            check_released_withdrawal(
+                &Vec::<u8>::try_from(admin_identity.cursor)?,
+                &Vec::<u8>::try_from(node_stake_info.identity().cursor)?,
+                &input_stake_data,
+                &checkpoint,
+                bytes_to_u64(&node_stake_info.inauguration_era()),
+                release_stake_amount,
+                0,
+                0,
+            )?;
+        }
+        MODE::SLASH => {
+            debug!("slash mode");
+            let slash_proof = match witness_lock {
+                Some(value) => SlashProof::decode(&value.raw_data())?,
+                None => return Err(Error::BadSlashProof),
+            };
+            let input_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Input)?;
+            let output_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Output)?;
+            let slash_rate = input_stake_data.slash_rate();
+            if slash_rate > slash::MAX_SLASH_RATE {
+                return Err(Error::StakeDataError);
+            }
+
+            // get checkpoint data from celldeps
+            let checkpoint =
+                get_checkpoint_from_celldeps(&input_stake_data.checkpoint_type_hash())?;
+            let era = bytes_to_u64(&checkpoint.era());
+
+            // check the proof is signed by the slashed node within the last unbonding_eras
+            let period_interval = bytes_to_u32(&checkpoint.period_interval()) as u64;
+            let era_period = bytes_to_u32(&checkpoint.era_period()) as u64;
+            if period_interval == 0 || era_period == 0 {
+                return Err(Error::CheckpointDataError);
+            }
+            let slash_era = slash_proof.votes()[0].block_number() / period_interval / era_period;
+            if slash_era > era
+                || slash_era.saturating_add(bytes_to_u64(&input_stake_data.unbonding_eras())) < era
+            {
+                return Err(Error::BadSlashProof);
+            }
+
+            // the appended stake_info removes the stake of the slashed node from era + 2, and
+            // marks the node as slashed by a zeroed bls_pub_key, which can never be registered
+            // again for lack of a proof of possession
+            let inauguration_era = era.checked_add(2).ok_or(Error::CheckpointDataError)?;
+            let node_stake_info =
+                get_slashing_stake_info(&input_stake_data, &output_stake_data, inauguration_era)?;
+            let node_identity = Vec::<u8>::try_from(node_stake_info.identity().cursor)?;
+            let input_stake_infos = input_stake_data.stake_infos();
+            let mut latest_stake_info = None;
+            for stake_info in protocol::stake::get_stake_infos_by_era(&input_stake_infos, u64::MAX)?
+            {
+                if Vec::<u8>::try_from(stake_info.identity().cursor.clone())? == node_identity {
+                    latest_stake_info = Some(stake_info);
+                    break;
+                }
+            }
+            let latest_stake_info = latest_stake_info.ok_or(Error::SlashTargetError)?;
+            if bytes_to_u128(&node_stake_info.stake_amount()) != 0
+                || node_stake_info.l2_address() != latest_stake_info.l2_address()
+                || node_stake_info.bls_pub_key().iter().any(|byte| *byte != 0)
+            {
+                return Err(Error::SlashTargetError);
+            }
+
+            // a node which has been slashed after slash_era can not be slashed by the same proof
+            // again
+            for i in 0..input_stake_infos.len() {
+                let stake_info = input_stake_infos.get(i);
+                if Vec::<u8>::try_from(stake_info.identity().cursor)? == node_identity
+                    && bytes_to_u64(&stake_info.inauguration_era()) > slash_era
+                    && stake_info.bls_pub_key().iter().all(|byte| *byte == 0)
+                {
+                    return Err(Error::SlashTargetError);
+                }
+            }
+
+            // only votes of a validator count in L2, so the offender must be in the validator set
+            // of slash_era, and a node which has unstaked since can still be slashed
+            let mut offender_stake_info = None;
+            for validator in protocol::stake::get_validators_by_era(&input_stake_data, slash_era)? {
+                if Vec::<u8>::try_from(validator.identity().cursor.clone())? == node_identity {
+                    offender_stake_info = Some(validator);
+                    break;
+                }
+            }
+            let offender_stake_info = offender_stake_info.ok_or(Error::SlashTargetError)?;
+            if !verify_slash_proof(&slash_proof, &offender_stake_info.bls_pub_key()) {
+                return Err(Error::BadSlashProof);
+            }
+
+            // check the latest stake of node leaves AT cells under stake lock, while the stake
+            // under unbonding is still held by the Withdrawal AT cell of node
+            let stake_amount = bytes_to_u128(&latest_stake_info.stake_amount());
+            let sudt_type_hash = input_stake_data.sudt_type_hash();
+            let stake_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let input_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Input)?;
+            let output_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Output)?;
+            if input_at_amount.checked_sub(output_at_amount) != Some(stake_amount) {
+                return Err(Error::StakeAmountMismatch);
+            }
+            check_total_locked_amount(&output_stake_data, output_at_amount)?;
+
+            // slash_rate of the stake and of the Withdrawal AT cells of node is burned, and the
+            // rest of the stake is released to the Withdrawal AT cell of node, where AT unstaked
+            // by node and still under unbonding must be slashed as well
+            let slashed_stake_amount =
+                slash::get_slashed_amount(stake_amount, slash_rate).ok_or(Error::StakeDataError)?;
+            let unbonding_amount =
+                get_unbonding_stake_amount(&input_stake_infos, &node_identity, era)?;
+            let slashed_withdrawal_amount = check_released_withdrawal(
+                &Vec::<u8>::try_from(admin_identity.cursor)?,
+                &node_identity,
+                &input_stake_data,
+                &checkpoint,
+                inauguration_era,
+                stake_amount
+                    .checked_sub(slashed_stake_amount)
+                    .ok_or(Error::StakeDataError)?,
+                slash_rate,
+                unbonding_amount,
+            )?;
+            let burned_at_amount = sudt::get_total_amount(None, &sudt_type_hash, Source::Input)?
+                .checked_sub(sudt::get_total_amount(
+                    None,
+                    &sudt_type_hash,
+                    Source::Output,
+                )?)
+                .ok_or(Error::SlashedATNotBurned)?;
+            if Some(burned_at_amount) != slashed_stake_amount.checked_add(slashed_withdrawal_amount)
+            {
+                return Err(Error::SlashedATNotBurned);
+            }
+        }
+        MODE::DELEGATE => {
+            debug!("delegate mode");
+            let input_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Input)?;
+            let output_stake_data = get_stake_data_by_type_hash(&type_id_hash, Source::Output)?;
+
+            // get checkpoint data from celldeps
+            let checkpoint =
+                get_checkpoint_from_celldeps(&input_stake_data.checkpoint_type_hash())?;
+            let era = bytes_to_u64(&checkpoint.era());
+
+            // check the appended delegate_info is authorized by the companion cell of its delegator
+            let delegate_info = get_appended_delegate_info(&input_stake_data, &output_stake_data)?;
+            let delegator_identity =
+                Vec::<u8>::try_from(delegate_info.delegator_identity().cursor)?;
+            let validator_identity =
+                Vec::<u8>::try_from(delegate_info.validator_identity().cursor)?;
+            if !get_companion_identities(&type_id_hash)?.contains_key(&delegator_identity) {
+                return Err(Error::CompanionCellEmpty);
+            }
+            let delegated_amount =
+                get_replaced_delegate_amount(&input_stake_data.delegate_infos(), &delegate_info)?;
+            let amount = bytes_to_u128(&delegate_info.amount());
+            let inauguration_era = bytes_to_u64(&delegate_info.inauguration_era());
+
+            let sudt_type_hash = input_stake_data.sudt_type_hash();
+            let stake_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+            let input_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Input)?;
+            let output_at_amount =
+                sudt::get_total_amount(Some(&stake_lock_hash), &sudt_type_hash, Source::Output)?;
+            match amount.cmp(&delegated_amount) {
+                Ordering::Greater => {
+                    // AT can only be delegated to a staking node, and takes effect from era + 2
+                    let mut is_staking = false;
+                    for stake_info in protocol::stake::get_stake_infos_by_era(
+                        &input_stake_data.stake_infos(),
+                        u64::MAX,
+                    )? {
+                        if Vec::<u8>::try_from(stake_info.identity().cursor.clone())?
+                            == validator_identity
+                            && bytes_to_u128(&stake_info.stake_amount()) > 0
+                        {
+                            is_staking = true;
+                            break;
+                        }
+                    }
+                    if !is_staking {
+                        return Err(Error::DelegateInfoError);
+                    }
+                    if Some(inauguration_era) != era.checked_add(2) {
+                        return Err(Error::BadInaugurationEra);
+                    }
+                    if output_at_amount.checked_sub(input_at_amount)
+                        != Some(amount - delegated_amount)
+                    {
+                        return Err(Error::StakeAmountMismatch);
+                    }
+                }
+                Ordering::Less => {
+                    // undelegated AT takes effect no earlier than era + unbonding_eras, and never
+                    // changes the applied or applying validator set, and goes to the Withdrawal AT
+                    // cell of delegator
+                    let unbonding_era = era
+                        .checked_add(bytes_to_u64(&input_stake_data.unbonding_eras()))
+                        .ok_or(Error::StakeDataError)?;
+                    let delegate_era = era.checked_add(2).ok_or(Error::CheckpointDataError)?;
+                    if inauguration_era < core::cmp::max(unbonding_era, delegate_era) {
+                        return Err(Error::BadInaugurationEra);
+                    }
+                    let release_amount = delegated_amount - amount;
+                    if input_at_amount.checked_sub(output_at_amount) != Some(release_amount) {
+                        return Err(Error::StakeAmountMismatch);
+                    }
+                    check_released_withdrawal(
+                        &Vec::<u8>::try_from(admin_identity.cursor)?,
+                        &delegator_identity,
+                        &input_stake_data,
+                        &checkpoint,
+                        inauguration_era,
+                        release_amount,
+                        0,
+                        0,
+                    )?;
+                }
+                Ordering::Equal => return Err(Error::DelegateInfoError),
+            }
+            check_total_locked_amount(&output_stake_data, output_at_amount)?;
         }
     }
 
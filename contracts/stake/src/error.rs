@@ -1,6 +1,8 @@
 use ckb_std::error::SysError;
+use protocol::{rlp, stake, sudt, MoleculeError};
 
 /// Error
+#[allow(clippy::enum_variant_names)]
 #[repr(i8)]
 pub enum Error {
     IndexOutOfBound = 1,
@@ -21,6 +23,21 @@ pub enum Error {
     CompanionModeError,
     UpdateModeError,
     DumplicateInputStakeInfo,
+    NotApplyStakeInfoError,
+    BadInaugurationEra,
+    CompanionCellEmpty,
+    BadSudtDataFormat,
+    ATAmountOverflow,
+    StakeAmountMismatch,
+    BadWithdrawalData,
+    BadWithdrawalPeriod,
+    WithdrawalAmountMismatch,
+    BadSlashProof,
+    SlashTargetError,
+    SlashedATNotBurned,
+    DelegateInfoError,
+    BadProofOfPossession,
+    UnbondingATNotSlashed,
 }
 
 impl From<SysError> for Error {
@@ -35,3 +52,37 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl From<rlp::Error> for Error {
+    fn from(_: rlp::Error) -> Self {
+        Self::BadSlashProof
+    }
+}
+
+impl From<MoleculeError> for Error {
+    fn from(_: MoleculeError) -> Self {
+        Self::Encoding
+    }
+}
+
+impl From<sudt::Error> for Error {
+    fn from(err: sudt::Error) -> Self {
+        use sudt::Error::*;
+        match err {
+            Sys(err) => Self::from(err),
+            BadSudtDataFormat => Self::BadSudtDataFormat,
+            BadWithdrawalData => Self::BadWithdrawalData,
+            AmountOverflow => Self::ATAmountOverflow,
+        }
+    }
+}
+
+impl From<stake::Error> for Error {
+    fn from(err: stake::Error) -> Self {
+        use stake::Error::*;
+        match err {
+            Molecule(err) => Self::from(err),
+            WeightOverflow => Self::ATAmountOverflow,
+        }
+    }
+}
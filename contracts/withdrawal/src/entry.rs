@@ -1,5 +1,5 @@
 // Import from `core` instead of from `std` since we are in no-std mode
-use core::result::Result;
+use core::{convert::TryFrom, result::Result};
 
 // Import heap related library from `alloc`
 // https://doc.rust-lang.org/alloc/index.html
@@ -9,69 +9,143 @@ use alloc::vec::Vec;
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::{bytes::Bytes, prelude::*},
+    ckb_types::{bytes::Bytes, packed::Script, prelude::*},
     debug,
     high_level::{
-        load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script, load_witness_args,
-        QueryIter,
+        load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type_hash, load_script,
+        load_witness_args, QueryIter,
     },
 };
 
 use crate::error::Error;
-use protocol::{axon, Cursor};
+use protocol::{axon, bytes_to_u64, mode, slash, sudt, withdrawal, Cursor};
 
+#[allow(clippy::upper_case_acronyms)]
 enum MODE {
     ACP,
     BURN,
     UNLOCK,
+    SLASH,
+    SWEEP,
 }
 
-fn bytes_to_u128(bytes: &Vec<u8>) -> u128 {
-    let mut array: [u8; 16] = [0u8; 16];
-    array.copy_from_slice(bytes.as_slice());
-    u128::from_le_bytes(array)
+fn get_checkpoint_from_celldeps(
+    checkpoint_cell_type_hash: &[u8],
+) -> Result<axon::CheckpointLockCellData, Error> {
+    let mut checkpoint_data = None;
+    QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .enumerate()
+        .map(|(i, type_hash)| {
+            if type_hash.unwrap_or([0u8; 32]) == checkpoint_cell_type_hash {
+                if checkpoint_data.is_some() {
+                    return Err(Error::BadCheckpointCelldep);
+                }
+                let data = load_cell_data(i, Source::CellDep);
+                if data.is_err() {
+                    return Err(Error::BadCheckpointCelldep);
+                }
+                let data: axon::CheckpointLockCellData = Cursor::from(data.unwrap()).into();
+                data.verify()?;
+                checkpoint_data = Some(data);
+            }
+            Ok(())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    checkpoint_data.ok_or(Error::CheckpointCelldepEmpty)
+}
+
+// check every cell in `source` under the withdrawal lock of `withdrawal_lock_hash` is typed by
+// AT, so no cell under this lock is left out of the sum of AT
+fn check_withdrawal_type_hash(
+    withdrawal_lock_hash: &[u8],
+    at_type_hash: &[u8; 32],
+    source: Source,
+) -> Result<(), Error> {
+    for (i, lock_hash) in QueryIter::new(load_cell_lock_hash, source).enumerate() {
+        if lock_hash[..] != withdrawal_lock_hash[..] {
+            continue;
+        }
+        match load_cell_type_hash(i, source)? {
+            Some(type_hash) if &type_hash == at_type_hash => {}
+            Some(_) => return Err(Error::BadWithdrawalTypeHash),
+            None => return Err(Error::SomeWithdrawalTypeEmpty),
+        }
+    }
+    Ok(())
 }
 
-fn bytes_to_u64(bytes: &Vec<u8>) -> u64 {
-    let mut array: [u8; 8] = [0u8; 8];
-    array.copy_from_slice(bytes.as_slice());
-    u64::from_le_bytes(array)
+// find stake cell in inputs which is unlocked in slash mode, and return the admin_identity of its
+// stake lock with its stake_data
+fn get_slashing_stake_data(
+    stake_type_hash: &[u8],
+) -> Result<(Vec<u8>, axon::StakeLockCellData), Error> {
+    let stake_index = QueryIter::new(load_cell_type_hash, Source::Input)
+        .position(|type_hash| type_hash.unwrap_or([0u8; 32]) == stake_type_hash)
+        .ok_or(Error::StakeCellEmpty)?;
+    // witness of stake lock group is placed at its first input
+    let stake_lock_hash = load_cell_lock_hash(stake_index, Source::Input)?;
+    let group_index = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .position(|lock_hash| lock_hash == stake_lock_hash)
+        .ok_or(Error::StakeCellEmpty)?;
+    let input_type = load_witness_args(group_index, Source::Input)
+        .map_err(|_| Error::StakeNotSlashing)?
+        .input_type()
+        .to_opt();
+    match input_type {
+        Some(value) if value.raw_data().to_vec() == [mode::SLASH] => {}
+        _ => return Err(Error::StakeNotSlashing),
+    }
+    let stake_args: Bytes = load_cell_lock(stake_index, Source::Input)?.args().unpack();
+    let stake_args: axon::StakeLockArgs = Cursor::from(stake_args.to_vec()).into();
+    stake_args.verify()?;
+    let data = load_cell_data(stake_index, Source::Input)?;
+    let stake_data: axon::StakeLockCellData = Cursor::from(data).into();
+    stake_data.verify()?;
+    Ok((
+        Vec::<u8>::try_from(stake_args.admin_identity().cursor)?,
+        stake_data,
+    ))
 }
 
-fn get_total_sudt_by_script_hash(
-    cell_lock_hash: &[u8; 32],
-    cell_type_hash: &[u8; 32],
-    source: Source,
+// sum up AT of cells in inputs under withdrawal locks of this code which expire to
+// `treasury_lock_hash`, and whose lock groups are unlocked in sweep mode
+fn get_total_swept_amount(
+    script: &Script,
+    at_type_hash: &[u8; 32],
+    treasury_lock_hash: &[u8],
 ) -> Result<u128, Error> {
-    let total_amount = QueryIter::new(load_cell_lock_hash, source)
-        .enumerate()
-        .map(|(i, lock_hash)| {
-            let mut amount = 0;
-            if &lock_hash == cell_lock_hash {
-                let type_hash = {
-                    let type_hash = load_cell_type_hash(i, source);
-                    if let Err(_) = type_hash {
-                        return Err(Error::BadWithdrawalTypeHash);
-                    }
-                    match type_hash.unwrap() {
-                        Some(value) => value,
-                        None => return Err(Error::SomeWithdrawalTypeEmpty),
-                    }
-                };
-                if &type_hash == cell_type_hash {
-                    let data = load_cell_data(i, source);
-                    if data.is_err() || data.as_ref().unwrap().len() != 24 {
-                        return Err(Error::BadWithdrawalData);
-                    }
-                    amount = bytes_to_u128(&data.unwrap()[..16].to_vec());
-                }
-            }
-            Ok(amount)
-        })
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .sum::<u128>();
-    Ok(total_amount)
+    let mut swept_amount = 0u128;
+    for (i, lock) in QueryIter::new(load_cell_lock, Source::Input).enumerate() {
+        if lock.code_hash().as_slice() != script.code_hash().as_slice()
+            || lock.hash_type().as_slice() != script.hash_type().as_slice()
+            || load_cell_type_hash(i, Source::Input)? != Some(*at_type_hash)
+        {
+            continue;
+        }
+        let args: Bytes = lock.args().unpack();
+        let withdrawal_args: axon::WithdrawalLockArgs = Cursor::from(args.to_vec()).into();
+        withdrawal_args.verify()?;
+        match withdrawal_args.expiry() {
+            Some(expiry) if expiry.treasury_lock_hash() == treasury_lock_hash => {}
+            _ => continue,
+        }
+        // witness of withdrawal lock group is placed at its first input
+        let lock_hash = load_cell_lock_hash(i, Source::Input)?;
+        let group_index = QueryIter::new(load_cell_lock_hash, Source::Input)
+            .position(|hash| hash == lock_hash)
+            .ok_or(Error::ItemMissing)?;
+        let input_type = load_witness_args(group_index, Source::Input)
+            .ok()
+            .and_then(|witness| witness.input_type().to_opt());
+        match input_type {
+            Some(value) if value.raw_data().to_vec() == [mode::SWEEP] => {}
+            _ => continue,
+        }
+        swept_amount = swept_amount
+            .checked_add(sudt::get_amount(&load_cell_data(i, Source::Input)?)?)
+            .ok_or(Error::TotalSudtAmountMismatch)?;
+    }
+    Ok(swept_amount)
 }
 
 pub fn main() -> Result<(), Error> {
@@ -80,9 +154,11 @@ pub fn main() -> Result<(), Error> {
 
     // extract parameters from lock_args
     let withdrawal_args: axon::WithdrawalLockArgs = Cursor::from(args.to_vec()).into();
+    withdrawal_args.verify()?;
     let admin_identity = withdrawal_args.admin_identity();
     let checkpoint_cell_type_hash = withdrawal_args.checkpoint_cell_type_hash();
     let node_identity = withdrawal_args.node_identity();
+    let expiry = withdrawal_args.expiry();
 
     // identify contract mode by witness
     let mode = match load_witness_args(0, Source::GroupInput) {
@@ -91,10 +167,11 @@ pub fn main() -> Result<(), Error> {
             if value.is_none() || value.as_ref().unwrap().len() != 1 {
                 return Err(Error::BadWitnessInputType);
             }
-            if value.unwrap().raw_data().to_vec().first().unwrap() == &0 {
-                MODE::BURN
-            } else {
-                MODE::UNLOCK
+            match value.unwrap().raw_data()[0] {
+                mode::BURN => MODE::BURN,
+                mode::SLASH => MODE::SLASH,
+                mode::SWEEP => MODE::SWEEP,
+                _ => MODE::UNLOCK,
             }
         }
         Err(_) => MODE::ACP,
@@ -136,39 +213,163 @@ pub fn main() -> Result<(), Error> {
             if !secp256k1::verify_signature(&mut node_identity.unwrap().content()) {
                 return Err(Error::SignatureMismatch);
             }
-            // load checkpoint cell_data from celldeps
-            let mut find_checkpoint = false;
-            QueryIter::new(load_cell_type_hash, Source::CellDep)
-                .enumerate()
-                .map(|(i, type_hash)| {
-                    if type_hash.unwrap_or([0u8; 32]) == checkpoint_cell_type_hash.as_slice() {
-                        assert!(find_checkpoint == false);
-                        find_checkpoint = true;
-                        let checkpoint_period = {
-                            let data = load_cell_data(i, Source::CellDep);
-                            if let Err(_) = data {
-                                return Err(Error::BadCheckpointCelldep);
-                            }
-                            let checkpoint_data: axon::CheckpointLockCellData =
-                                Cursor::from(data.unwrap()).into();
-                            bytes_to_u64(&checkpoint_data.period())
-                        };
-                        let withdrawal_period = {
-                            let data = load_cell_data(0, Source::GroupInput);
-                            if data.is_err() || data.as_ref().unwrap().len() != 24 {
-                                return Err(Error::BadWithdrawalData);
-                            }
-                            bytes_to_u64(&data.unwrap()[16..].to_vec())
-                        };
-                        if withdrawal_period > checkpoint_period {
-                            return Err(Error::BadWithdrawalPeriod);
-                        }
+            // check every Withdrawal AT cell in this group has matured in checkpoint period
+            let checkpoint_data = get_checkpoint_from_celldeps(&checkpoint_cell_type_hash)?;
+            let checkpoint_period = bytes_to_u64(&checkpoint_data.period());
+            QueryIter::new(load_cell_data, Source::GroupInput)
+                .map(|data| {
+                    if data.len() != protocol::withdrawal::CELL_DATA_SIZE {
+                        return Err(Error::BadWithdrawalData);
+                    }
+                    let withdrawal_data: axon::WithdrawalLockCellData = Cursor::from(data).into();
+                    let withdrawal_period = bytes_to_u64(&withdrawal_data.period());
+                    if withdrawal_period > checkpoint_period {
+                        return Err(Error::BadWithdrawalPeriod);
+                    }
+                    Ok(())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // AT can be partially unlocked, and the rest goes back to Withdrawal AT cells under
+            // this lock with its original period
+            let mut withdrawal_amounts =
+                withdrawal::get_withdrawal_buckets(&script, &at_type_hash, Source::Input)?;
+            for (amount, period) in
+                withdrawal::get_withdrawal_cells(&script, &at_type_hash, Source::Output)?
+            {
+                match withdrawal_amounts.get_mut(&period) {
+                    Some(withdrawal_amount) => {
+                        *withdrawal_amount = withdrawal_amount
+                            .checked_sub(amount)
+                            .ok_or(Error::TotalSudtAmountMismatch)?
+                    }
+                    None => return Err(Error::BadWithdrawalPeriod),
+                }
+            }
+        }
+        MODE::SLASH => {
+            debug!("slash mode");
+            let node_identity =
+                Vec::<u8>::try_from(node_identity.ok_or(Error::NodeIdentityEmpty)?.cursor)?;
+            // check stake cell is slashing the node of this withdrawal lock, and the slash proof
+            // is verified by stake lock
+            let checkpoint_data = get_checkpoint_from_celldeps(&checkpoint_cell_type_hash)?;
+            let (stake_admin_identity, stake_data) =
+                get_slashing_stake_data(&checkpoint_data.stake_type_hash())?;
+            if stake_data.checkpoint_type_hash() != checkpoint_cell_type_hash {
+                return Err(Error::StakeNotSlashing);
+            }
+            // only the Withdrawal AT cell which stake lock releases AT to is slashed, so stake
+            // lock accounts for every AT taken away from this lock
+            let withdrawal_expiry = checkpoint_data
+                .withdrawal_expiry()
+                .map(|expiry| Vec::<u8>::try_from(expiry.cursor))
+                .transpose()?;
+            let withdrawal_lock = withdrawal::lock_script(
+                &checkpoint_data.withdrawal_lock_code_hash(),
+                &withdrawal::lock_args(
+                    &stake_admin_identity,
+                    &checkpoint_cell_type_hash,
+                    Some(&node_identity),
+                    withdrawal_expiry.as_deref(),
+                ),
+            );
+            if withdrawal_lock.as_slice() != script.as_slice() {
+                return Err(Error::StakeNotSlashing);
+            }
+            let output_stake_data: axon::StakeLockCellData = {
+                let stake_type_hash = checkpoint_data.stake_type_hash();
+                let stake_index = QueryIter::new(load_cell_type_hash, Source::Output)
+                    .position(|type_hash| {
+                        type_hash.unwrap_or([0u8; 32]) == stake_type_hash.as_slice()
+                    })
+                    .ok_or(Error::StakeCellEmpty)?;
+                Cursor::from(load_cell_data(stake_index, Source::Output)?).into()
+            };
+            output_stake_data.verify()?;
+            let output_stake_infos = output_stake_data.stake_infos();
+            if output_stake_infos.len() == 0 {
+                return Err(Error::StakeNotSlashing);
+            }
+            let slashed_identity = Vec::<u8>::try_from(
+                output_stake_infos
+                    .get(output_stake_infos.len() - 1)
+                    .identity()
+                    .cursor,
+            )?;
+            if slashed_identity != node_identity {
+                return Err(Error::StakeNotSlashing);
+            }
+
+            // at most slash_rate of AT under this lock is taken away, and the rest can not be
+            // unlocked earlier
+            let withdrawal_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?.to_vec();
+            let input_total_sudt =
+                sudt::get_total_amount(Some(&withdrawal_lock_hash), &at_type_hash, Source::Input)?;
+            let output_total_sudt =
+                sudt::get_total_amount(Some(&withdrawal_lock_hash), &at_type_hash, Source::Output)?;
+            let slashed_sudt = slash::get_slashed_amount(input_total_sudt, stake_data.slash_rate())
+                .ok_or(Error::BadSlashRate)?;
+            let remained_sudt = input_total_sudt
+                .checked_sub(slashed_sudt)
+                .ok_or(Error::TotalSudtAmountMismatch)?;
+            if output_total_sudt < remained_sudt {
+                return Err(Error::TotalSudtAmountMismatch);
+            }
+            let input_period =
+                withdrawal::get_withdrawal_cells(&script, &at_type_hash, Source::Input)?
+                    .into_iter()
+                    .map(|(_, period)| period)
+                    .max();
+            let output_period =
+                withdrawal::get_withdrawal_cells(&script, &at_type_hash, Source::Output)?
+                    .into_iter()
+                    .map(|(_, period)| period)
+                    .min();
+            if let (Some(input_period), Some(output_period)) = (input_period, output_period) {
+                if output_period < input_period {
+                    return Err(Error::BadWithdrawalPeriod);
+                }
+            }
+        }
+        MODE::SWEEP => {
+            debug!("sweep mode");
+            let expiry = expiry.ok_or(Error::WithdrawalExpiryEmpty)?;
+            // check admin signature
+            if !secp256k1::verify_signature(&mut admin_identity.content()) {
+                return Err(Error::SignatureMismatch);
+            }
+            // check every Withdrawal AT cell in this group has been unclaimed for expiry_periods
+            // after its period
+            let checkpoint_data = get_checkpoint_from_celldeps(&checkpoint_cell_type_hash)?;
+            let checkpoint_period = bytes_to_u64(&checkpoint_data.period());
+            let expiry_periods = bytes_to_u64(&expiry.expiry_periods());
+            QueryIter::new(load_cell_data, Source::GroupInput)
+                .map(|data| {
+                    if data.len() != protocol::withdrawal::CELL_DATA_SIZE {
+                        return Err(Error::BadWithdrawalData);
+                    }
+                    let withdrawal_data: axon::WithdrawalLockCellData = Cursor::from(data).into();
+                    let expiry_period = bytes_to_u64(&withdrawal_data.period())
+                        .checked_add(expiry_periods)
+                        .ok_or(Error::WithdrawalNotExpired)?;
+                    if expiry_period > checkpoint_period {
+                        return Err(Error::WithdrawalNotExpired);
                     }
                     Ok(())
                 })
                 .collect::<Result<Vec<_>, _>>()?;
-            if !find_checkpoint {
-                return Err(Error::CheckpointCelldepEmpty);
+
+            // check AT swept by every lock group of this treasury lock goes to the treasury lock,
+            // otherwise one output could be claimed by each of several swept groups
+            let treasury_lock_hash = expiry.treasury_lock_hash();
+            let swept_amount = get_total_swept_amount(&script, &at_type_hash, &treasury_lock_hash)?;
+            let input_treasury_sudt =
+                sudt::get_total_amount(Some(&treasury_lock_hash), &at_type_hash, Source::Input)?;
+            let output_treasury_sudt =
+                sudt::get_total_amount(Some(&treasury_lock_hash), &at_type_hash, Source::Output)?;
+            if output_treasury_sudt.checked_sub(input_treasury_sudt) < Some(swept_amount) {
+                return Err(Error::TotalSudtAmountMismatch);
             }
         }
         MODE::ACP => {
@@ -177,17 +378,43 @@ pub fn main() -> Result<(), Error> {
                 return Err(Error::NodeIdentityEmpty);
             }
             // check input and output total amount in withdrawal cell_data
-            let withdrawal_lock_hash: [u8; 32] = load_cell_lock_hash(0, Source::GroupInput)?;
+            let withdrawal_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?.to_vec();
+            check_withdrawal_type_hash(&withdrawal_lock_hash, &at_type_hash, Source::Input)?;
+            check_withdrawal_type_hash(&withdrawal_lock_hash, &at_type_hash, Source::Output)?;
             let input_total_sudt =
-                get_total_sudt_by_script_hash(&withdrawal_lock_hash, &at_type_hash, Source::Input)?;
-            let output_total_sudt = get_total_sudt_by_script_hash(
-                &withdrawal_lock_hash,
-                &at_type_hash,
-                Source::Output,
-            )?;
+                sudt::get_total_amount(Some(&withdrawal_lock_hash), &at_type_hash, Source::Input)?;
+            let output_total_sudt =
+                sudt::get_total_amount(Some(&withdrawal_lock_hash), &at_type_hash, Source::Output)?;
             if output_total_sudt < input_total_sudt {
                 return Err(Error::TotalSudtAmountMismatch);
             }
+
+            // periods of existing Withdrawal AT cells can not be changed, so AT can be merged into
+            // Withdrawal AT cells of existing periods, or deposited into new Withdrawal AT cells
+            // whose period is later than all existing ones
+            let input_buckets =
+                withdrawal::get_withdrawal_buckets(&script, &at_type_hash, Source::Input)?;
+            let output_buckets =
+                withdrawal::get_withdrawal_buckets(&script, &at_type_hash, Source::Output)?;
+            let latest_period = input_buckets.keys().max().cloned().unwrap_or(0);
+            if input_buckets
+                .keys()
+                .any(|period| !output_buckets.contains_key(period))
+                || output_buckets
+                    .keys()
+                    .any(|period| !input_buckets.contains_key(period) && period <= &latest_period)
+            {
+                return Err(Error::BadWithdrawalPeriod);
+            }
+
+            // AT can not be moved out of any existing bucket, so new buckets only hold AT newly
+            // deposited
+            if input_buckets
+                .iter()
+                .any(|(period, amount)| output_buckets[period] < *amount)
+            {
+                return Err(Error::WithdrawalBucketMismatch);
+            }
         }
     }
 
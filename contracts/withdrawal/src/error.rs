@@ -1,4 +1,5 @@
 use ckb_std::error::SysError;
+use protocol::{sudt, MoleculeError};
 
 /// Error
 #[repr(i8)]
@@ -20,6 +21,13 @@ pub enum Error {
     BadWithdrawalTypeHash,
     SomeWithdrawalTypeEmpty,
     TotalSudtAmountMismatch,
+    StakeCellEmpty,
+    StakeNotSlashing,
+    WithdrawalBucketMismatch,
+    WithdrawalExpiryEmpty,
+    WithdrawalNotExpired,
+    BadSudtDataFormat,
+    BadSlashRate,
 }
 
 impl From<SysError> for Error {
@@ -34,3 +42,21 @@ impl From<SysError> for Error {
         }
     }
 }
+
+impl From<MoleculeError> for Error {
+    fn from(_: MoleculeError) -> Self {
+        Self::Encoding
+    }
+}
+
+impl From<sudt::Error> for Error {
+    fn from(err: sudt::Error) -> Self {
+        use sudt::Error::*;
+        match err {
+            Sys(err) => Self::from(err),
+            BadSudtDataFormat => Self::BadSudtDataFormat,
+            BadWithdrawalData => Self::BadWithdrawalData,
+            AmountOverflow => Self::TotalSudtAmountMismatch,
+        }
+    }
+}
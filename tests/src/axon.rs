@@ -3810,6 +3810,7 @@ impl ::core::fmt::Display for StakeInfo {
         write!(f, ", {}: {}", "bls_pub_key", self.bls_pub_key())?;
         write!(f, ", {}: {}", "stake_amount", self.stake_amount())?;
         write!(f, ", {}: {}", "inauguration_era", self.inauguration_era())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3820,19 +3821,20 @@ impl ::core::fmt::Display for StakeInfo {
 impl ::core::default::Default for StakeInfo {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            198, 0, 0, 0, 24, 0, 0, 0, 57, 0, 0, 0, 77, 0, 0, 0, 174, 0, 0, 0, 190, 0, 0, 0, 33, 0,
-            0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            203, 0, 0, 0, 28, 0, 0, 0, 61, 0, 0, 0, 81, 0, 0, 0, 178, 0, 0, 0, 194, 0, 0, 0, 202,
+            0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
         ];
         StakeInfo::new_unchecked(v.into())
     }
 }
 impl StakeInfo {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -3876,11 +3878,17 @@ impl StakeInfo {
     pub fn inauguration_era(&self) -> Byte8 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte8::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte8::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte8::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> StakeInfoReader<'r> {
@@ -3915,6 +3923,7 @@ impl molecule::prelude::Entity for StakeInfo {
             .bls_pub_key(self.bls_pub_key())
             .stake_amount(self.stake_amount())
             .inauguration_era(self.inauguration_era())
+            .commission_rate(self.commission_rate())
     }
 }
 #[derive(Clone, Copy)]
@@ -3941,6 +3950,7 @@ impl<'r> ::core::fmt::Display for StakeInfoReader<'r> {
         write!(f, ", {}: {}", "bls_pub_key", self.bls_pub_key())?;
         write!(f, ", {}: {}", "stake_amount", self.stake_amount())?;
         write!(f, ", {}: {}", "inauguration_era", self.inauguration_era())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3949,7 +3959,7 @@ impl<'r> ::core::fmt::Display for StakeInfoReader<'r> {
     }
 }
 impl<'r> StakeInfoReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -3993,11 +4003,17 @@ impl<'r> StakeInfoReader<'r> {
     pub fn inauguration_era(&self) -> Byte8Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte8Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte8Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte8Reader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -4055,6 +4071,7 @@ impl<'r> molecule::prelude::Reader<'r> for StakeInfoReader<'r> {
         Byte97Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Byte16Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Byte8Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
@@ -4065,9 +4082,10 @@ pub struct StakeInfoBuilder {
     pub(crate) bls_pub_key: Byte97,
     pub(crate) stake_amount: Byte16,
     pub(crate) inauguration_era: Byte8,
+    pub(crate) commission_rate: Byte,
 }
 impl StakeInfoBuilder {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn identity(mut self, v: Identity) -> Self {
         self.identity = v;
         self
@@ -4088,6 +4106,10 @@ impl StakeInfoBuilder {
         self.inauguration_era = v;
         self
     }
+    pub fn commission_rate(mut self, v: Byte) -> Self {
+        self.commission_rate = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for StakeInfoBuilder {
     type Entity = StakeInfo;
@@ -4099,6 +4121,7 @@ impl molecule::prelude::Builder for StakeInfoBuilder {
             + self.bls_pub_key.as_slice().len()
             + self.stake_amount.as_slice().len()
             + self.inauguration_era.as_slice().len()
+            + self.commission_rate.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -4113,6 +4136,8 @@ impl molecule::prelude::Builder for StakeInfoBuilder {
         total_size += self.stake_amount.as_slice().len();
         offsets.push(total_size);
         total_size += self.inauguration_era.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_rate.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -4122,6 +4147,7 @@ impl molecule::prelude::Builder for StakeInfoBuilder {
         writer.write_all(self.bls_pub_key.as_slice())?;
         writer.write_all(self.stake_amount.as_slice())?;
         writer.write_all(self.inauguration_era.as_slice())?;
+        writer.write_all(self.commission_rate.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -4629,8 +4655,8 @@ impl molecule::prelude::Builder for IdentityOptBuilder {
     }
 }
 #[derive(Clone)]
-pub struct SelectionLockArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for SelectionLockArgs {
+pub struct DelegateInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DelegateInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4639,21 +4665,23 @@ impl ::core::fmt::LowerHex for SelectionLockArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for SelectionLockArgs {
+impl ::core::fmt::Debug for DelegateInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for SelectionLockArgs {
+impl ::core::fmt::Display for DelegateInfo {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "omni_lock_hash", self.omni_lock_hash())?;
+        write!(f, "{}: {}", "delegator_identity", self.delegator_identity())?;
         write!(
             f,
             ", {}: {}",
-            "checkpoint_lock_hash",
-            self.checkpoint_lock_hash()
+            "validator_identity",
+            self.validator_identity()
         )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "inauguration_era", self.inauguration_era())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4661,18 +4689,19 @@ impl ::core::fmt::Display for SelectionLockArgs {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for SelectionLockArgs {
+impl ::core::default::Default for DelegateInfo {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            76, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            110, 0, 0, 0, 20, 0, 0, 0, 53, 0, 0, 0, 86, 0, 0, 0, 102, 0, 0, 0, 33, 0, 0, 0, 12, 0,
+            0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33,
+            0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        SelectionLockArgs::new_unchecked(v.into())
+        DelegateInfo::new_unchecked(v.into())
     }
 }
-impl SelectionLockArgs {
-    pub const FIELD_COUNT: usize = 2;
+impl DelegateInfo {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4689,31 +4718,43 @@ impl SelectionLockArgs {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn omni_lock_hash(&self) -> Byte32 {
+    pub fn delegator_identity(&self) -> Identity {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+        Identity::new_unchecked(self.0.slice(start..end))
     }
-    pub fn checkpoint_lock_hash(&self) -> Byte32 {
+    pub fn validator_identity(&self) -> Identity {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn inauguration_era(&self) -> Byte8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte8::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Byte8::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> SelectionLockArgsReader<'r> {
-        SelectionLockArgsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> DelegateInfoReader<'r> {
+        DelegateInfoReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for SelectionLockArgs {
-    type Builder = SelectionLockArgsBuilder;
-    const NAME: &'static str = "SelectionLockArgs";
+impl molecule::prelude::Entity for DelegateInfo {
+    type Builder = DelegateInfoBuilder;
+    const NAME: &'static str = "DelegateInfo";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        SelectionLockArgs(data)
+        DelegateInfo(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -4722,23 +4763,25 @@ impl molecule::prelude::Entity for SelectionLockArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SelectionLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        DelegateInfoReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        SelectionLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        DelegateInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .omni_lock_hash(self.omni_lock_hash())
-            .checkpoint_lock_hash(self.checkpoint_lock_hash())
+            .delegator_identity(self.delegator_identity())
+            .validator_identity(self.validator_identity())
+            .amount(self.amount())
+            .inauguration_era(self.inauguration_era())
     }
 }
 #[derive(Clone, Copy)]
-pub struct SelectionLockArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for SelectionLockArgsReader<'r> {
+pub struct DelegateInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DelegateInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4747,21 +4790,23 @@ impl<'r> ::core::fmt::LowerHex for SelectionLockArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for SelectionLockArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for DelegateInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for SelectionLockArgsReader<'r> {
+impl<'r> ::core::fmt::Display for DelegateInfoReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "omni_lock_hash", self.omni_lock_hash())?;
+        write!(f, "{}: {}", "delegator_identity", self.delegator_identity())?;
         write!(
             f,
             ", {}: {}",
-            "checkpoint_lock_hash",
-            self.checkpoint_lock_hash()
+            "validator_identity",
+            self.validator_identity()
         )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "inauguration_era", self.inauguration_era())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -4769,8 +4814,8 @@ impl<'r> ::core::fmt::Display for SelectionLockArgsReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> SelectionLockArgsReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> DelegateInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -4787,31 +4832,43 @@ impl<'r> SelectionLockArgsReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn omni_lock_hash(&self) -> Byte32Reader<'r> {
+    pub fn delegator_identity(&self) -> IdentityReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn checkpoint_lock_hash(&self) -> Byte32Reader<'r> {
+    pub fn validator_identity(&self) -> IdentityReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn inauguration_era(&self) -> Byte8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte8Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            Byte8Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for SelectionLockArgsReader<'r> {
-    type Entity = SelectionLockArgs;
-    const NAME: &'static str = "SelectionLockArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for DelegateInfoReader<'r> {
+    type Entity = DelegateInfo;
+    const NAME: &'static str = "DelegateInfoReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        SelectionLockArgsReader(slice)
+        DelegateInfoReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -4853,60 +4910,80 @@ impl<'r> molecule::prelude::Reader<'r> for SelectionLockArgsReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        IdentityReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte8Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct SelectionLockArgsBuilder {
-    pub(crate) omni_lock_hash: Byte32,
-    pub(crate) checkpoint_lock_hash: Byte32,
+pub struct DelegateInfoBuilder {
+    pub(crate) delegator_identity: Identity,
+    pub(crate) validator_identity: Identity,
+    pub(crate) amount: Byte16,
+    pub(crate) inauguration_era: Byte8,
 }
-impl SelectionLockArgsBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn omni_lock_hash(mut self, v: Byte32) -> Self {
-        self.omni_lock_hash = v;
+impl DelegateInfoBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn delegator_identity(mut self, v: Identity) -> Self {
+        self.delegator_identity = v;
         self
     }
-    pub fn checkpoint_lock_hash(mut self, v: Byte32) -> Self {
-        self.checkpoint_lock_hash = v;
+    pub fn validator_identity(mut self, v: Identity) -> Self {
+        self.validator_identity = v;
+        self
+    }
+    pub fn amount(mut self, v: Byte16) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn inauguration_era(mut self, v: Byte8) -> Self {
+        self.inauguration_era = v;
         self
     }
 }
-impl molecule::prelude::Builder for SelectionLockArgsBuilder {
-    type Entity = SelectionLockArgs;
-    const NAME: &'static str = "SelectionLockArgsBuilder";
+impl molecule::prelude::Builder for DelegateInfoBuilder {
+    type Entity = DelegateInfo;
+    const NAME: &'static str = "DelegateInfoBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.omni_lock_hash.as_slice().len()
-            + self.checkpoint_lock_hash.as_slice().len()
+            + self.delegator_identity.as_slice().len()
+            + self.validator_identity.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.inauguration_era.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.omni_lock_hash.as_slice().len();
+        total_size += self.delegator_identity.as_slice().len();
         offsets.push(total_size);
-        total_size += self.checkpoint_lock_hash.as_slice().len();
+        total_size += self.validator_identity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.inauguration_era.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.omni_lock_hash.as_slice())?;
-        writer.write_all(self.checkpoint_lock_hash.as_slice())?;
+        writer.write_all(self.delegator_identity.as_slice())?;
+        writer.write_all(self.validator_identity.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.inauguration_era.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        SelectionLockArgs::new_unchecked(inner.into())
+        DelegateInfo::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct StakeLockArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for StakeLockArgs {
+pub struct DelegateInfoVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DelegateInfoVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -4915,83 +4992,75 @@ impl ::core::fmt::LowerHex for StakeLockArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for StakeLockArgs {
+impl ::core::fmt::Debug for DelegateInfoVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for StakeLockArgs {
+impl ::core::fmt::Display for DelegateInfoVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
-        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
-        write!(f, ", {}: {}", "node_identity", self.node_identity())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for StakeLockArgs {
+impl ::core::default::Default for DelegateInfoVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            81, 0, 0, 0, 16, 0, 0, 0, 49, 0, 0, 0, 81, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        StakeLockArgs::new_unchecked(v.into())
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        DelegateInfoVec::new_unchecked(v.into())
     }
 }
-impl StakeLockArgs {
-    pub const FIELD_COUNT: usize = 3;
+impl DelegateInfoVec {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn admin_identity(&self) -> Identity {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Identity::new_unchecked(self.0.slice(start..end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn type_id_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn get(&self, idx: usize) -> Option<DelegateInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn node_identity(&self) -> IdentityOpt {
+    pub fn get_unchecked(&self, idx: usize) -> DelegateInfo {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            IdentityOpt::new_unchecked(self.0.slice(start..end))
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            DelegateInfo::new_unchecked(self.0.slice(start..))
         } else {
-            IdentityOpt::new_unchecked(self.0.slice(start..))
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            DelegateInfo::new_unchecked(self.0.slice(start..end))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> StakeLockArgsReader<'r> {
-        StakeLockArgsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> DelegateInfoVecReader<'r> {
+        DelegateInfoVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for StakeLockArgs {
-    type Builder = StakeLockArgsBuilder;
-    const NAME: &'static str = "StakeLockArgs";
+impl molecule::prelude::Entity for DelegateInfoVec {
+    type Builder = DelegateInfoVecBuilder;
+    const NAME: &'static str = "DelegateInfoVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        StakeLockArgs(data)
+        DelegateInfoVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5000,24 +5069,21 @@ impl molecule::prelude::Entity for StakeLockArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        StakeLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        DelegateInfoVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        StakeLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        DelegateInfoVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .admin_identity(self.admin_identity())
-            .type_id_hash(self.type_id_hash())
-            .node_identity(self.node_identity())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct StakeLockArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for StakeLockArgsReader<'r> {
+pub struct DelegateInfoVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DelegateInfoVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5026,73 +5092,69 @@ impl<'r> ::core::fmt::LowerHex for StakeLockArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for StakeLockArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for DelegateInfoVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for StakeLockArgsReader<'r> {
+impl<'r> ::core::fmt::Display for DelegateInfoVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
-        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
-        write!(f, ", {}: {}", "node_identity", self.node_identity())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> StakeLockArgsReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+impl<'r> DelegateInfoVecReader<'r> {
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
+    pub fn item_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn admin_identity(&self) -> IdentityReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn type_id_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<DelegateInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn node_identity(&self) -> IdentityOptReader<'r> {
+    pub fn get_unchecked(&self, idx: usize) -> DelegateInfoReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            IdentityOptReader::new_unchecked(&self.as_slice()[start..end])
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            DelegateInfoReader::new_unchecked(&self.as_slice()[start..])
         } else {
-            IdentityOptReader::new_unchecked(&self.as_slice()[start..])
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            DelegateInfoReader::new_unchecked(&self.as_slice()[start..end])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for StakeLockArgsReader<'r> {
-    type Entity = StakeLockArgs;
-    const NAME: &'static str = "StakeLockArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for DelegateInfoVecReader<'r> {
+    type Entity = DelegateInfoVec;
+    const NAME: &'static str = "DelegateInfoVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        StakeLockArgsReader(slice)
+        DelegateInfoVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -5107,11 +5169,16 @@ impl<'r> molecule::prelude::Reader<'r> for StakeLockArgsReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+        if slice_len == molecule::NUMBER_SIZE {
             return Ok(());
         }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -5120,12 +5187,6 @@ impl<'r> molecule::prelude::Reader<'r> for StakeLockArgsReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -5134,70 +5195,129 @@ impl<'r> molecule::prelude::Reader<'r> for StakeLockArgsReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        IdentityOptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            DelegateInfoReader::verify(&slice[start..end], compatible)?;
+        }
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct StakeLockArgsBuilder {
-    pub(crate) admin_identity: Identity,
-    pub(crate) type_id_hash: Byte32,
-    pub(crate) node_identity: IdentityOpt,
-}
-impl StakeLockArgsBuilder {
-    pub const FIELD_COUNT: usize = 3;
-    pub fn admin_identity(mut self, v: Identity) -> Self {
-        self.admin_identity = v;
+pub struct DelegateInfoVecBuilder(pub(crate) Vec<DelegateInfo>);
+impl DelegateInfoVecBuilder {
+    pub fn set(mut self, v: Vec<DelegateInfo>) -> Self {
+        self.0 = v;
         self
     }
-    pub fn type_id_hash(mut self, v: Byte32) -> Self {
-        self.type_id_hash = v;
+    pub fn push(mut self, v: DelegateInfo) -> Self {
+        self.0.push(v);
         self
     }
-    pub fn node_identity(mut self, v: IdentityOpt) -> Self {
-        self.node_identity = v;
+    pub fn extend<T: ::core::iter::IntoIterator<Item = DelegateInfo>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
         self
     }
 }
-impl molecule::prelude::Builder for StakeLockArgsBuilder {
-    type Entity = StakeLockArgs;
-    const NAME: &'static str = "StakeLockArgsBuilder";
+impl molecule::prelude::Builder for DelegateInfoVecBuilder {
+    type Entity = DelegateInfoVec;
+    const NAME: &'static str = "DelegateInfoVecBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.admin_identity.as_slice().len()
-            + self.type_id_hash.as_slice().len()
-            + self.node_identity.as_slice().len()
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.admin_identity.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.type_id_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.node_identity.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
         }
-        writer.write_all(self.admin_identity.as_slice())?;
-        writer.write_all(self.type_id_hash.as_slice())?;
-        writer.write_all(self.node_identity.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        StakeLockArgs::new_unchecked(inner.into())
+        DelegateInfoVec::new_unchecked(inner.into())
+    }
+}
+pub struct DelegateInfoVecIterator(DelegateInfoVec, usize, usize);
+impl ::core::iter::Iterator for DelegateInfoVecIterator {
+    type Item = DelegateInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for DelegateInfoVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for DelegateInfoVec {
+    type Item = DelegateInfo;
+    type IntoIter = DelegateInfoVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        DelegateInfoVecIterator(self, 0, len)
+    }
+}
+impl<'r> DelegateInfoVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> DelegateInfoVecReaderIterator<'t, 'r> {
+        DelegateInfoVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct DelegateInfoVecReaderIterator<'t, 'r>(&'t DelegateInfoVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for DelegateInfoVecReaderIterator<'t, 'r> {
+    type Item = DelegateInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for DelegateInfoVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
     }
 }
 #[derive(Clone)]
-pub struct StakeLockCellData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for StakeLockCellData {
+pub struct AuthorizedLock(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AuthorizedLock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5206,103 +5326,47 @@ impl ::core::fmt::LowerHex for StakeLockCellData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for StakeLockCellData {
+impl ::core::fmt::Debug for AuthorizedLock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for StakeLockCellData {
+impl ::core::fmt::Display for AuthorizedLock {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "stake_infos", self.stake_infos())?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_type_hash",
-            self.checkpoint_type_hash()
-        )?;
-        write!(f, ", {}: {}", "sudt_type_hash", self.sudt_type_hash())?;
-        write!(f, ", {}: {}", "quorum_size", self.quorum_size())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
+        write!(f, "{}: {}", "role", self.role())?;
+        write!(f, ", {}: {}", "lock_hash", self.lock_hash())?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for StakeLockCellData {
+impl ::core::default::Default for AuthorizedLock {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            94, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 29, 0, 0, 0, 61, 0, 0, 0, 93, 0, 0, 0, 0, 4, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
         ];
-        StakeLockCellData::new_unchecked(v.into())
+        AuthorizedLock::new_unchecked(v.into())
     }
 }
-impl StakeLockCellData {
-    pub const FIELD_COUNT: usize = 5;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn version(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn stake_infos(&self) -> StakeInfoVec {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        StakeInfoVec::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn checkpoint_type_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn sudt_type_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+impl AuthorizedLock {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [1, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn role(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
     }
-    pub fn quorum_size(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte::new_unchecked(self.0.slice(start..end))
-        } else {
-            Byte::new_unchecked(self.0.slice(start..))
-        }
+    pub fn lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(1..33))
     }
-    pub fn as_reader<'r>(&'r self) -> StakeLockCellDataReader<'r> {
-        StakeLockCellDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> AuthorizedLockReader<'r> {
+        AuthorizedLockReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for StakeLockCellData {
-    type Builder = StakeLockCellDataBuilder;
-    const NAME: &'static str = "StakeLockCellData";
+impl molecule::prelude::Entity for AuthorizedLock {
+    type Builder = AuthorizedLockBuilder;
+    const NAME: &'static str = "AuthorizedLock";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        StakeLockCellData(data)
+        AuthorizedLock(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5311,26 +5375,23 @@ impl molecule::prelude::Entity for StakeLockCellData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        StakeLockCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        AuthorizedLockReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        StakeLockCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AuthorizedLockReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .version(self.version())
-            .stake_infos(self.stake_infos())
-            .checkpoint_type_hash(self.checkpoint_type_hash())
-            .sudt_type_hash(self.sudt_type_hash())
-            .quorum_size(self.quorum_size())
+            .role(self.role())
+            .lock_hash(self.lock_hash())
     }
 }
 #[derive(Clone, Copy)]
-pub struct StakeLockCellDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for StakeLockCellDataReader<'r> {
+pub struct AuthorizedLockReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AuthorizedLockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5339,217 +5400,90 @@ impl<'r> ::core::fmt::LowerHex for StakeLockCellDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for StakeLockCellDataReader<'r> {
+impl<'r> ::core::fmt::Debug for AuthorizedLockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for StakeLockCellDataReader<'r> {
+impl<'r> ::core::fmt::Display for AuthorizedLockReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "stake_infos", self.stake_infos())?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_type_hash",
-            self.checkpoint_type_hash()
-        )?;
-        write!(f, ", {}: {}", "sudt_type_hash", self.sudt_type_hash())?;
-        write!(f, ", {}: {}", "quorum_size", self.quorum_size())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
+        write!(f, "{}: {}", "role", self.role())?;
+        write!(f, ", {}: {}", "lock_hash", self.lock_hash())?;
         write!(f, " }}")
     }
 }
-impl<'r> StakeLockCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn version(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn stake_infos(&self) -> StakeInfoVecReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        StakeInfoVecReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn checkpoint_type_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn sudt_type_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+impl<'r> AuthorizedLockReader<'r> {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [1, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn role(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
     }
-    pub fn quorum_size(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            ByteReader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            ByteReader::new_unchecked(&self.as_slice()[start..])
-        }
+    pub fn lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[1..33])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for StakeLockCellDataReader<'r> {
-    type Entity = StakeLockCellData;
-    const NAME: &'static str = "StakeLockCellDataReader";
+impl<'r> molecule::prelude::Reader<'r> for AuthorizedLockReader<'r> {
+    type Entity = AuthorizedLock;
+    const NAME: &'static str = "AuthorizedLockReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        StakeLockCellDataReader(slice)
+        AuthorizedLockReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        StakeInfoVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct StakeLockCellDataBuilder {
-    pub(crate) version: Byte,
-    pub(crate) stake_infos: StakeInfoVec,
-    pub(crate) checkpoint_type_hash: Byte32,
-    pub(crate) sudt_type_hash: Byte32,
-    pub(crate) quorum_size: Byte,
+pub struct AuthorizedLockBuilder {
+    pub(crate) role: Byte,
+    pub(crate) lock_hash: Byte32,
 }
-impl StakeLockCellDataBuilder {
-    pub const FIELD_COUNT: usize = 5;
-    pub fn version(mut self, v: Byte) -> Self {
-        self.version = v;
-        self
-    }
-    pub fn stake_infos(mut self, v: StakeInfoVec) -> Self {
-        self.stake_infos = v;
-        self
-    }
-    pub fn checkpoint_type_hash(mut self, v: Byte32) -> Self {
-        self.checkpoint_type_hash = v;
-        self
-    }
-    pub fn sudt_type_hash(mut self, v: Byte32) -> Self {
-        self.sudt_type_hash = v;
+impl AuthorizedLockBuilder {
+    pub const TOTAL_SIZE: usize = 33;
+    pub const FIELD_SIZES: [usize; 2] = [1, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn role(mut self, v: Byte) -> Self {
+        self.role = v;
         self
     }
-    pub fn quorum_size(mut self, v: Byte) -> Self {
-        self.quorum_size = v;
+    pub fn lock_hash(mut self, v: Byte32) -> Self {
+        self.lock_hash = v;
         self
     }
 }
-impl molecule::prelude::Builder for StakeLockCellDataBuilder {
-    type Entity = StakeLockCellData;
-    const NAME: &'static str = "StakeLockCellDataBuilder";
+impl molecule::prelude::Builder for AuthorizedLockBuilder {
+    type Entity = AuthorizedLock;
+    const NAME: &'static str = "AuthorizedLockBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.version.as_slice().len()
-            + self.stake_infos.as_slice().len()
-            + self.checkpoint_type_hash.as_slice().len()
-            + self.sudt_type_hash.as_slice().len()
-            + self.quorum_size.as_slice().len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.version.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.stake_infos.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.checkpoint_type_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.sudt_type_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.quorum_size.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.version.as_slice())?;
-        writer.write_all(self.stake_infos.as_slice())?;
-        writer.write_all(self.checkpoint_type_hash.as_slice())?;
-        writer.write_all(self.sudt_type_hash.as_slice())?;
-        writer.write_all(self.quorum_size.as_slice())?;
+        writer.write_all(self.role.as_slice())?;
+        writer.write_all(self.lock_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        StakeLockCellData::new_unchecked(inner.into())
+        AuthorizedLock::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct CheckpointLockArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for CheckpointLockArgs {
+pub struct AuthorizedLockVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for AuthorizedLockVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5558,76 +5492,65 @@ impl ::core::fmt::LowerHex for CheckpointLockArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for CheckpointLockArgs {
+impl ::core::fmt::Debug for AuthorizedLockVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for CheckpointLockArgs {
+impl ::core::fmt::Display for AuthorizedLockVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
-        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for CheckpointLockArgs {
+impl ::core::default::Default for AuthorizedLockVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            77, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        CheckpointLockArgs::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        AuthorizedLockVec::new_unchecked(v.into())
     }
 }
-impl CheckpointLockArgs {
-    pub const FIELD_COUNT: usize = 2;
+impl AuthorizedLockVec {
+    pub const ITEM_SIZE: usize = 33;
     pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn admin_identity(&self) -> Identity {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Identity::new_unchecked(self.0.slice(start..end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn type_id_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn get(&self, idx: usize) -> Option<AuthorizedLock> {
+        if idx >= self.len() {
+            None
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Some(self.get_unchecked(idx))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> CheckpointLockArgsReader<'r> {
-        CheckpointLockArgsReader::new_unchecked(self.as_slice())
+    pub fn get_unchecked(&self, idx: usize) -> AuthorizedLock {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        AuthorizedLock::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> AuthorizedLockVecReader<'r> {
+        AuthorizedLockVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for CheckpointLockArgs {
-    type Builder = CheckpointLockArgsBuilder;
-    const NAME: &'static str = "CheckpointLockArgs";
+impl molecule::prelude::Entity for AuthorizedLockVec {
+    type Builder = AuthorizedLockVecBuilder;
+    const NAME: &'static str = "AuthorizedLockVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        CheckpointLockArgs(data)
+        AuthorizedLockVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -5636,23 +5559,21 @@ impl molecule::prelude::Entity for CheckpointLockArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        CheckpointLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        AuthorizedLockVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        CheckpointLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        AuthorizedLockVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .admin_identity(self.admin_identity())
-            .type_id_hash(self.type_id_hash())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct CheckpointLockArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for CheckpointLockArgsReader<'r> {
+pub struct AuthorizedLockVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for AuthorizedLockVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -5661,156 +5582,2943 @@ impl<'r> ::core::fmt::LowerHex for CheckpointLockArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for CheckpointLockArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for AuthorizedLockVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for CheckpointLockArgsReader<'r> {
+impl<'r> ::core::fmt::Display for AuthorizedLockVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
-        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> CheckpointLockArgsReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+impl<'r> AuthorizedLockVecReader<'r> {
+    pub const ITEM_SIZE: usize = 33;
     pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn admin_identity(&self) -> IdentityReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn type_id_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get(&self, idx: usize) -> Option<AuthorizedLockReader<'r>> {
+        if idx >= self.len() {
+            None
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            Some(self.get_unchecked(idx))
         }
     }
+    pub fn get_unchecked(&self, idx: usize) -> AuthorizedLockReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        AuthorizedLockReader::new_unchecked(&self.as_slice()[start..end])
+    }
 }
-impl<'r> molecule::prelude::Reader<'r> for CheckpointLockArgsReader<'r> {
-    type Entity = CheckpointLockArgs;
-    const NAME: &'static str = "CheckpointLockArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for AuthorizedLockVecReader<'r> {
+    type Entity = AuthorizedLockVec;
+    const NAME: &'static str = "AuthorizedLockVecReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        CheckpointLockArgsReader(slice)
+        AuthorizedLockVecReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
         if slice_len < molecule::NUMBER_SIZE {
             return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        let total_size = molecule::unpack_number(slice) as usize;
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct AuthorizedLockVecBuilder(pub(crate) Vec<AuthorizedLock>);
+impl AuthorizedLockVecBuilder {
+    pub const ITEM_SIZE: usize = 33;
+    pub fn set(mut self, v: Vec<AuthorizedLock>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: AuthorizedLock) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = AuthorizedLock>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
         }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        self
+    }
+}
+impl molecule::prelude::Builder for AuthorizedLockVecBuilder {
+    type Entity = AuthorizedLockVec;
+    const NAME: &'static str = "AuthorizedLockVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
         }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        AuthorizedLockVec::new_unchecked(inner.into())
+    }
+}
+pub struct AuthorizedLockVecIterator(AuthorizedLockVec, usize, usize);
+impl ::core::iter::Iterator for AuthorizedLockVecIterator {
+    type Item = AuthorizedLock;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
         }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+    }
+}
+impl ::core::iter::ExactSizeIterator for AuthorizedLockVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for AuthorizedLockVec {
+    type Item = AuthorizedLock;
+    type IntoIter = AuthorizedLockVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        AuthorizedLockVecIterator(self, 0, len)
+    }
+}
+impl<'r> AuthorizedLockVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> AuthorizedLockVecReaderIterator<'t, 'r> {
+        AuthorizedLockVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct AuthorizedLockVecReaderIterator<'t, 'r>(&'t AuthorizedLockVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for AuthorizedLockVecReaderIterator<'t, 'r> {
+    type Item = AuthorizedLockReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for AuthorizedLockVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SelectionRecovery(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SelectionRecovery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SelectionRecovery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SelectionRecovery {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recovery_lock_hash", self.recovery_lock_hash())?;
+        write!(f, ", {}: {}", "since_epochs", self.since_epochs())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SelectionRecovery {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SelectionRecovery::new_unchecked(v.into())
+    }
+}
+impl SelectionRecovery {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn recovery_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn since_epochs(&self) -> Byte8 {
+        Byte8::new_unchecked(self.0.slice(32..40))
+    }
+    pub fn as_reader<'r>(&'r self) -> SelectionRecoveryReader<'r> {
+        SelectionRecoveryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SelectionRecovery {
+    type Builder = SelectionRecoveryBuilder;
+    const NAME: &'static str = "SelectionRecovery";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SelectionRecovery(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionRecoveryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionRecoveryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .recovery_lock_hash(self.recovery_lock_hash())
+            .since_epochs(self.since_epochs())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SelectionRecoveryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SelectionRecoveryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SelectionRecoveryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SelectionRecoveryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recovery_lock_hash", self.recovery_lock_hash())?;
+        write!(f, ", {}: {}", "since_epochs", self.since_epochs())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> SelectionRecoveryReader<'r> {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn recovery_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn since_epochs(&self) -> Byte8Reader<'r> {
+        Byte8Reader::new_unchecked(&self.as_slice()[32..40])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SelectionRecoveryReader<'r> {
+    type Entity = SelectionRecovery;
+    const NAME: &'static str = "SelectionRecoveryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SelectionRecoveryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SelectionRecoveryBuilder {
+    pub(crate) recovery_lock_hash: Byte32,
+    pub(crate) since_epochs: Byte8,
+}
+impl SelectionRecoveryBuilder {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn recovery_lock_hash(mut self, v: Byte32) -> Self {
+        self.recovery_lock_hash = v;
+        self
+    }
+    pub fn since_epochs(mut self, v: Byte8) -> Self {
+        self.since_epochs = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SelectionRecoveryBuilder {
+    type Entity = SelectionRecovery;
+    const NAME: &'static str = "SelectionRecoveryBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.recovery_lock_hash.as_slice())?;
+        writer.write_all(self.since_epochs.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SelectionRecovery::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SelectionRecoveryOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SelectionRecoveryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SelectionRecoveryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SelectionRecoveryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for SelectionRecoveryOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        SelectionRecoveryOpt::new_unchecked(v.into())
+    }
+}
+impl SelectionRecoveryOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<SelectionRecovery> {
+        if self.is_none() {
+            None
+        } else {
+            Some(SelectionRecovery::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SelectionRecoveryOptReader<'r> {
+        SelectionRecoveryOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SelectionRecoveryOpt {
+    type Builder = SelectionRecoveryOptBuilder;
+    const NAME: &'static str = "SelectionRecoveryOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SelectionRecoveryOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionRecoveryOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionRecoveryOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SelectionRecoveryOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SelectionRecoveryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SelectionRecoveryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SelectionRecoveryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> SelectionRecoveryOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<SelectionRecoveryReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(SelectionRecoveryReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SelectionRecoveryOptReader<'r> {
+    type Entity = SelectionRecoveryOpt;
+    const NAME: &'static str = "SelectionRecoveryOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SelectionRecoveryOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            SelectionRecoveryReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SelectionRecoveryOptBuilder(pub(crate) Option<SelectionRecovery>);
+impl SelectionRecoveryOptBuilder {
+    pub fn set(mut self, v: Option<SelectionRecovery>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SelectionRecoveryOptBuilder {
+    type Entity = SelectionRecoveryOpt;
+    const NAME: &'static str = "SelectionRecoveryOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SelectionRecoveryOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SelectionLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SelectionLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SelectionLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SelectionLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "omni_lock_hash", self.omni_lock_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_lock_hash",
+            self.checkpoint_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "authorized_locks", self.authorized_locks())?;
+        write!(f, ", {}: {}", "recovery", self.recovery())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SelectionLockArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            88, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ];
+        SelectionLockArgs::new_unchecked(v.into())
+    }
+}
+impl SelectionLockArgs {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn omni_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn checkpoint_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn authorized_locks(&self) -> AuthorizedLockVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        AuthorizedLockVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn recovery(&self) -> SelectionRecoveryOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            SelectionRecoveryOpt::new_unchecked(self.0.slice(start..end))
+        } else {
+            SelectionRecoveryOpt::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SelectionLockArgsReader<'r> {
+        SelectionLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SelectionLockArgs {
+    type Builder = SelectionLockArgsBuilder;
+    const NAME: &'static str = "SelectionLockArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SelectionLockArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SelectionLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .omni_lock_hash(self.omni_lock_hash())
+            .checkpoint_lock_hash(self.checkpoint_lock_hash())
+            .authorized_locks(self.authorized_locks())
+            .recovery(self.recovery())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SelectionLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SelectionLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SelectionLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SelectionLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "omni_lock_hash", self.omni_lock_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_lock_hash",
+            self.checkpoint_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "authorized_locks", self.authorized_locks())?;
+        write!(f, ", {}: {}", "recovery", self.recovery())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SelectionLockArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn omni_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn checkpoint_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn authorized_locks(&self) -> AuthorizedLockVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        AuthorizedLockVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn recovery(&self) -> SelectionRecoveryOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            SelectionRecoveryOptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SelectionRecoveryOptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SelectionLockArgsReader<'r> {
+    type Entity = SelectionLockArgs;
+    const NAME: &'static str = "SelectionLockArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SelectionLockArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        AuthorizedLockVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        SelectionRecoveryOptReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SelectionLockArgsBuilder {
+    pub(crate) omni_lock_hash: Byte32,
+    pub(crate) checkpoint_lock_hash: Byte32,
+    pub(crate) authorized_locks: AuthorizedLockVec,
+    pub(crate) recovery: SelectionRecoveryOpt,
+}
+impl SelectionLockArgsBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn omni_lock_hash(mut self, v: Byte32) -> Self {
+        self.omni_lock_hash = v;
+        self
+    }
+    pub fn checkpoint_lock_hash(mut self, v: Byte32) -> Self {
+        self.checkpoint_lock_hash = v;
+        self
+    }
+    pub fn authorized_locks(mut self, v: AuthorizedLockVec) -> Self {
+        self.authorized_locks = v;
+        self
+    }
+    pub fn recovery(mut self, v: SelectionRecoveryOpt) -> Self {
+        self.recovery = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SelectionLockArgsBuilder {
+    type Entity = SelectionLockArgs;
+    const NAME: &'static str = "SelectionLockArgsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.omni_lock_hash.as_slice().len()
+            + self.checkpoint_lock_hash.as_slice().len()
+            + self.authorized_locks.as_slice().len()
+            + self.recovery.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.omni_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.checkpoint_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.authorized_locks.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.recovery.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.omni_lock_hash.as_slice())?;
+        writer.write_all(self.checkpoint_lock_hash.as_slice())?;
+        writer.write_all(self.authorized_locks.as_slice())?;
+        writer.write_all(self.recovery.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SelectionLockArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct StakeLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StakeLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for StakeLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for StakeLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
+        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
+        write!(f, ", {}: {}", "node_identity", self.node_identity())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for StakeLockArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            81, 0, 0, 0, 16, 0, 0, 0, 49, 0, 0, 0, 81, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        StakeLockArgs::new_unchecked(v.into())
+    }
+}
+impl StakeLockArgs {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn admin_identity(&self) -> Identity {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn type_id_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn node_identity(&self) -> IdentityOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            IdentityOpt::new_unchecked(self.0.slice(start..end))
+        } else {
+            IdentityOpt::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> StakeLockArgsReader<'r> {
+        StakeLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for StakeLockArgs {
+    type Builder = StakeLockArgsBuilder;
+    const NAME: &'static str = "StakeLockArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        StakeLockArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StakeLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StakeLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .admin_identity(self.admin_identity())
+            .type_id_hash(self.type_id_hash())
+            .node_identity(self.node_identity())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct StakeLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StakeLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for StakeLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for StakeLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
+        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
+        write!(f, ", {}: {}", "node_identity", self.node_identity())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> StakeLockArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn admin_identity(&self) -> IdentityReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn type_id_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn node_identity(&self) -> IdentityOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            IdentityOptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            IdentityOptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for StakeLockArgsReader<'r> {
+    type Entity = StakeLockArgs;
+    const NAME: &'static str = "StakeLockArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        StakeLockArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        IdentityOptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct StakeLockArgsBuilder {
+    pub(crate) admin_identity: Identity,
+    pub(crate) type_id_hash: Byte32,
+    pub(crate) node_identity: IdentityOpt,
+}
+impl StakeLockArgsBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn admin_identity(mut self, v: Identity) -> Self {
+        self.admin_identity = v;
+        self
+    }
+    pub fn type_id_hash(mut self, v: Byte32) -> Self {
+        self.type_id_hash = v;
+        self
+    }
+    pub fn node_identity(mut self, v: IdentityOpt) -> Self {
+        self.node_identity = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for StakeLockArgsBuilder {
+    type Entity = StakeLockArgs;
+    const NAME: &'static str = "StakeLockArgsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.admin_identity.as_slice().len()
+            + self.type_id_hash.as_slice().len()
+            + self.node_identity.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.admin_identity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.type_id_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.node_identity.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.admin_identity.as_slice())?;
+        writer.write_all(self.type_id_hash.as_slice())?;
+        writer.write_all(self.node_identity.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        StakeLockArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct StakeLockCellData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for StakeLockCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for StakeLockCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for StakeLockCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "stake_infos", self.stake_infos())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_type_hash",
+            self.checkpoint_type_hash()
+        )?;
+        write!(f, ", {}: {}", "sudt_type_hash", self.sudt_type_hash())?;
+        write!(f, ", {}: {}", "quorum_size", self.quorum_size())?;
+        write!(f, ", {}: {}", "unbonding_eras", self.unbonding_eras())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
+        write!(f, ", {}: {}", "delegate_infos", self.delegate_infos())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for StakeLockCellData {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            119, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 41, 0, 0, 0, 73, 0, 0, 0, 105, 0, 0, 0, 106, 0,
+            0, 0, 114, 0, 0, 0, 115, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 4, 0, 0, 0,
+        ];
+        StakeLockCellData::new_unchecked(v.into())
+    }
+}
+impl StakeLockCellData {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn stake_infos(&self) -> StakeInfoVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        StakeInfoVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn checkpoint_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sudt_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn quorum_size(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn unbonding_eras(&self) -> Byte8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte8::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn slash_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_infos(&self) -> DelegateInfoVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            DelegateInfoVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            DelegateInfoVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> StakeLockCellDataReader<'r> {
+        StakeLockCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for StakeLockCellData {
+    type Builder = StakeLockCellDataBuilder;
+    const NAME: &'static str = "StakeLockCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        StakeLockCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StakeLockCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        StakeLockCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .version(self.version())
+            .stake_infos(self.stake_infos())
+            .checkpoint_type_hash(self.checkpoint_type_hash())
+            .sudt_type_hash(self.sudt_type_hash())
+            .quorum_size(self.quorum_size())
+            .unbonding_eras(self.unbonding_eras())
+            .slash_rate(self.slash_rate())
+            .delegate_infos(self.delegate_infos())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct StakeLockCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for StakeLockCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for StakeLockCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for StakeLockCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "stake_infos", self.stake_infos())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_type_hash",
+            self.checkpoint_type_hash()
+        )?;
+        write!(f, ", {}: {}", "sudt_type_hash", self.sudt_type_hash())?;
+        write!(f, ", {}: {}", "quorum_size", self.quorum_size())?;
+        write!(f, ", {}: {}", "unbonding_eras", self.unbonding_eras())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
+        write!(f, ", {}: {}", "delegate_infos", self.delegate_infos())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> StakeLockCellDataReader<'r> {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn stake_infos(&self) -> StakeInfoVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        StakeInfoVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn checkpoint_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sudt_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn quorum_size(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn unbonding_eras(&self) -> Byte8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte8Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn slash_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_infos(&self) -> DelegateInfoVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            DelegateInfoVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            DelegateInfoVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for StakeLockCellDataReader<'r> {
+    type Entity = StakeLockCellData;
+    const NAME: &'static str = "StakeLockCellDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        StakeLockCellDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        StakeInfoVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte8Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        ByteReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        DelegateInfoVecReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct StakeLockCellDataBuilder {
+    pub(crate) version: Byte,
+    pub(crate) stake_infos: StakeInfoVec,
+    pub(crate) checkpoint_type_hash: Byte32,
+    pub(crate) sudt_type_hash: Byte32,
+    pub(crate) quorum_size: Byte,
+    pub(crate) unbonding_eras: Byte8,
+    pub(crate) slash_rate: Byte,
+    pub(crate) delegate_infos: DelegateInfoVec,
+}
+impl StakeLockCellDataBuilder {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn version(mut self, v: Byte) -> Self {
+        self.version = v;
+        self
+    }
+    pub fn stake_infos(mut self, v: StakeInfoVec) -> Self {
+        self.stake_infos = v;
+        self
+    }
+    pub fn checkpoint_type_hash(mut self, v: Byte32) -> Self {
+        self.checkpoint_type_hash = v;
+        self
+    }
+    pub fn sudt_type_hash(mut self, v: Byte32) -> Self {
+        self.sudt_type_hash = v;
+        self
+    }
+    pub fn quorum_size(mut self, v: Byte) -> Self {
+        self.quorum_size = v;
+        self
+    }
+    pub fn unbonding_eras(mut self, v: Byte8) -> Self {
+        self.unbonding_eras = v;
+        self
+    }
+    pub fn slash_rate(mut self, v: Byte) -> Self {
+        self.slash_rate = v;
+        self
+    }
+    pub fn delegate_infos(mut self, v: DelegateInfoVec) -> Self {
+        self.delegate_infos = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for StakeLockCellDataBuilder {
+    type Entity = StakeLockCellData;
+    const NAME: &'static str = "StakeLockCellDataBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.version.as_slice().len()
+            + self.stake_infos.as_slice().len()
+            + self.checkpoint_type_hash.as_slice().len()
+            + self.sudt_type_hash.as_slice().len()
+            + self.quorum_size.as_slice().len()
+            + self.unbonding_eras.as_slice().len()
+            + self.slash_rate.as_slice().len()
+            + self.delegate_infos.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.version.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.stake_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.checkpoint_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sudt_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.quorum_size.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.unbonding_eras.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.slash_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_infos.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.stake_infos.as_slice())?;
+        writer.write_all(self.checkpoint_type_hash.as_slice())?;
+        writer.write_all(self.sudt_type_hash.as_slice())?;
+        writer.write_all(self.quorum_size.as_slice())?;
+        writer.write_all(self.unbonding_eras.as_slice())?;
+        writer.write_all(self.slash_rate.as_slice())?;
+        writer.write_all(self.delegate_infos.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        StakeLockCellData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CheckpointLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckpointLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckpointLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckpointLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
+        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CheckpointLockArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            77, 0, 0, 0, 12, 0, 0, 0, 45, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        CheckpointLockArgs::new_unchecked(v.into())
+    }
+}
+impl CheckpointLockArgs {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn admin_identity(&self) -> Identity {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn type_id_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CheckpointLockArgsReader<'r> {
+        CheckpointLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckpointLockArgs {
+    type Builder = CheckpointLockArgsBuilder;
+    const NAME: &'static str = "CheckpointLockArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckpointLockArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .admin_identity(self.admin_identity())
+            .type_id_hash(self.type_id_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckpointLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckpointLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckpointLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckpointLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "admin_identity", self.admin_identity())?;
+        write!(f, ", {}: {}", "type_id_hash", self.type_id_hash())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CheckpointLockArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn admin_identity(&self) -> IdentityReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn type_id_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckpointLockArgsReader<'r> {
+    type Entity = CheckpointLockArgs;
+    const NAME: &'static str = "CheckpointLockArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckpointLockArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CheckpointLockArgsBuilder {
+    pub(crate) admin_identity: Identity,
+    pub(crate) type_id_hash: Byte32,
+}
+impl CheckpointLockArgsBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn admin_identity(mut self, v: Identity) -> Self {
+        self.admin_identity = v;
+        self
+    }
+    pub fn type_id_hash(mut self, v: Byte32) -> Self {
+        self.type_id_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckpointLockArgsBuilder {
+    type Entity = CheckpointLockArgs;
+    const NAME: &'static str = "CheckpointLockArgsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.admin_identity.as_slice().len()
+            + self.type_id_hash.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.admin_identity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.type_id_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.admin_identity.as_slice())?;
+        writer.write_all(self.type_id_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckpointLockArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RewardSchedule(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RewardSchedule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RewardSchedule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RewardSchedule {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "decay_reward", self.decay_reward())?;
+        write!(f, ", {}: {}", "tail_reward", self.tail_reward())?;
+        write!(f, ", {}: {}", "supply_cap", self.supply_cap())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RewardSchedule {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            69, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 37, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RewardSchedule::new_unchecked(v.into())
+    }
+}
+impl RewardSchedule {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn decay_reward(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn tail_reward(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn supply_cap(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte16::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte16::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RewardScheduleReader<'r> {
+        RewardScheduleReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RewardSchedule {
+    type Builder = RewardScheduleBuilder;
+    const NAME: &'static str = "RewardSchedule";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RewardSchedule(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RewardScheduleReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RewardScheduleReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .version(self.version())
+            .decay_reward(self.decay_reward())
+            .tail_reward(self.tail_reward())
+            .supply_cap(self.supply_cap())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RewardScheduleReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RewardScheduleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RewardScheduleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RewardScheduleReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "decay_reward", self.decay_reward())?;
+        write!(f, ", {}: {}", "tail_reward", self.tail_reward())?;
+        write!(f, ", {}: {}", "supply_cap", self.supply_cap())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RewardScheduleReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn decay_reward(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tail_reward(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn supply_cap(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte16Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RewardScheduleReader<'r> {
+    type Entity = RewardSchedule;
+    const NAME: &'static str = "RewardScheduleReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RewardScheduleReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RewardScheduleBuilder {
+    pub(crate) version: Byte,
+    pub(crate) decay_reward: Byte16,
+    pub(crate) tail_reward: Byte16,
+    pub(crate) supply_cap: Byte16,
+}
+impl RewardScheduleBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn version(mut self, v: Byte) -> Self {
+        self.version = v;
+        self
+    }
+    pub fn decay_reward(mut self, v: Byte16) -> Self {
+        self.decay_reward = v;
+        self
+    }
+    pub fn tail_reward(mut self, v: Byte16) -> Self {
+        self.tail_reward = v;
+        self
+    }
+    pub fn supply_cap(mut self, v: Byte16) -> Self {
+        self.supply_cap = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RewardScheduleBuilder {
+    type Entity = RewardSchedule;
+    const NAME: &'static str = "RewardScheduleBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.version.as_slice().len()
+            + self.decay_reward.as_slice().len()
+            + self.tail_reward.as_slice().len()
+            + self.supply_cap.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.version.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.decay_reward.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tail_reward.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.supply_cap.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.decay_reward.as_slice())?;
+        writer.write_all(self.tail_reward.as_slice())?;
+        writer.write_all(self.supply_cap.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RewardSchedule::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CheckpointParams(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckpointParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckpointParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckpointParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "effective_era", self.effective_era())?;
+        write!(f, ", {}: {}", "unlock_period", self.unlock_period())?;
+        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_period", self.half_period())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CheckpointParams {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            56, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        CheckpointParams::new_unchecked(v.into())
+    }
+}
+impl CheckpointParams {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn effective_era(&self) -> Byte8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte8::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn unlock_period(&self) -> Byte4 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte4::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn base_reward(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn half_period(&self) -> Byte8 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte8::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte8::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CheckpointParamsReader<'r> {
+        CheckpointParamsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckpointParams {
+    type Builder = CheckpointParamsBuilder;
+    const NAME: &'static str = "CheckpointParams";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckpointParams(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointParamsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointParamsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .effective_era(self.effective_era())
+            .unlock_period(self.unlock_period())
+            .base_reward(self.base_reward())
+            .half_period(self.half_period())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckpointParamsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckpointParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckpointParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckpointParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "effective_era", self.effective_era())?;
+        write!(f, ", {}: {}", "unlock_period", self.unlock_period())?;
+        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_period", self.half_period())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CheckpointParamsReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn effective_era(&self) -> Byte8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte8Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn unlock_period(&self) -> Byte4Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte4Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn base_reward(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn half_period(&self) -> Byte8Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte8Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte8Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckpointParamsReader<'r> {
+    type Entity = CheckpointParams;
+    const NAME: &'static str = "CheckpointParamsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckpointParamsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte8Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte4Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte8Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CheckpointParamsBuilder {
+    pub(crate) effective_era: Byte8,
+    pub(crate) unlock_period: Byte4,
+    pub(crate) base_reward: Byte16,
+    pub(crate) half_period: Byte8,
+}
+impl CheckpointParamsBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn effective_era(mut self, v: Byte8) -> Self {
+        self.effective_era = v;
+        self
+    }
+    pub fn unlock_period(mut self, v: Byte4) -> Self {
+        self.unlock_period = v;
+        self
+    }
+    pub fn base_reward(mut self, v: Byte16) -> Self {
+        self.base_reward = v;
+        self
+    }
+    pub fn half_period(mut self, v: Byte8) -> Self {
+        self.half_period = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckpointParamsBuilder {
+    type Entity = CheckpointParams;
+    const NAME: &'static str = "CheckpointParamsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.effective_era.as_slice().len()
+            + self.unlock_period.as_slice().len()
+            + self.base_reward.as_slice().len()
+            + self.half_period.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.effective_era.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.unlock_period.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.base_reward.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.half_period.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.effective_era.as_slice())?;
+        writer.write_all(self.unlock_period.as_slice())?;
+        writer.write_all(self.base_reward.as_slice())?;
+        writer.write_all(self.half_period.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckpointParams::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct CheckpointParamsOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CheckpointParamsOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CheckpointParamsOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CheckpointParamsOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for CheckpointParamsOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        CheckpointParamsOpt::new_unchecked(v.into())
+    }
+}
+impl CheckpointParamsOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<CheckpointParams> {
+        if self.is_none() {
+            None
+        } else {
+            Some(CheckpointParams::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CheckpointParamsOptReader<'r> {
+        CheckpointParamsOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CheckpointParamsOpt {
+    type Builder = CheckpointParamsOptBuilder;
+    const NAME: &'static str = "CheckpointParamsOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CheckpointParamsOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointParamsOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CheckpointParamsOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CheckpointParamsOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CheckpointParamsOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CheckpointParamsOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CheckpointParamsOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> CheckpointParamsOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<CheckpointParamsReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(CheckpointParamsReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CheckpointParamsOptReader<'r> {
+    type Entity = CheckpointParamsOpt;
+    const NAME: &'static str = "CheckpointParamsOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CheckpointParamsOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            CheckpointParamsReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CheckpointParamsOptBuilder(pub(crate) Option<CheckpointParams>);
+impl CheckpointParamsOptBuilder {
+    pub fn set(mut self, v: Option<CheckpointParams>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CheckpointParamsOptBuilder {
+    type Entity = CheckpointParamsOpt;
+    const NAME: &'static str = "CheckpointParamsOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CheckpointParamsOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalExpiry(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalExpiry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalExpiry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalExpiry {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "expiry_periods", self.expiry_periods())?;
+        write!(
+            f,
+            ", {}: {}",
+            "treasury_lock_hash",
+            self.treasury_lock_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for WithdrawalExpiry {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        WithdrawalExpiry::new_unchecked(v.into())
+    }
+}
+impl WithdrawalExpiry {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn expiry_periods(&self) -> Byte8 {
+        Byte8::new_unchecked(self.0.slice(0..8))
+    }
+    pub fn treasury_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(8..40))
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalExpiryReader<'r> {
+        WithdrawalExpiryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalExpiry {
+    type Builder = WithdrawalExpiryBuilder;
+    const NAME: &'static str = "WithdrawalExpiry";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalExpiry(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalExpiryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalExpiryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .expiry_periods(self.expiry_periods())
+            .treasury_lock_hash(self.treasury_lock_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalExpiryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalExpiryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalExpiryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalExpiryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "expiry_periods", self.expiry_periods())?;
+        write!(
+            f,
+            ", {}: {}",
+            "treasury_lock_hash",
+            self.treasury_lock_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> WithdrawalExpiryReader<'r> {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn expiry_periods(&self) -> Byte8Reader<'r> {
+        Byte8Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+    pub fn treasury_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[8..40])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalExpiryReader<'r> {
+    type Entity = WithdrawalExpiry;
+    const NAME: &'static str = "WithdrawalExpiryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalExpiryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct WithdrawalExpiryBuilder {
+    pub(crate) expiry_periods: Byte8,
+    pub(crate) treasury_lock_hash: Byte32,
+}
+impl WithdrawalExpiryBuilder {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [8, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn expiry_periods(mut self, v: Byte8) -> Self {
+        self.expiry_periods = v;
+        self
+    }
+    pub fn treasury_lock_hash(mut self, v: Byte32) -> Self {
+        self.treasury_lock_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for WithdrawalExpiryBuilder {
+    type Entity = WithdrawalExpiry;
+    const NAME: &'static str = "WithdrawalExpiryBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.expiry_periods.as_slice())?;
+        writer.write_all(self.treasury_lock_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        WithdrawalExpiry::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct WithdrawalExpiryOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for WithdrawalExpiryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for WithdrawalExpiryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for WithdrawalExpiryOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for WithdrawalExpiryOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        WithdrawalExpiryOpt::new_unchecked(v.into())
+    }
+}
+impl WithdrawalExpiryOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<WithdrawalExpiry> {
+        if self.is_none() {
+            None
+        } else {
+            Some(WithdrawalExpiry::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> WithdrawalExpiryOptReader<'r> {
+        WithdrawalExpiryOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for WithdrawalExpiryOpt {
+    type Builder = WithdrawalExpiryOptBuilder;
+    const NAME: &'static str = "WithdrawalExpiryOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        WithdrawalExpiryOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalExpiryOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        WithdrawalExpiryOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct WithdrawalExpiryOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for WithdrawalExpiryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for WithdrawalExpiryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for WithdrawalExpiryOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> WithdrawalExpiryOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<WithdrawalExpiryReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(WithdrawalExpiryReader::new_unchecked(self.as_slice()))
         }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for WithdrawalExpiryOptReader<'r> {
+    type Entity = WithdrawalExpiryOpt;
+    const NAME: &'static str = "WithdrawalExpiryOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        WithdrawalExpiryOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            WithdrawalExpiryReader::verify(&slice[..], compatible)?;
         }
-        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct CheckpointLockArgsBuilder {
-    pub(crate) admin_identity: Identity,
-    pub(crate) type_id_hash: Byte32,
-}
-impl CheckpointLockArgsBuilder {
-    pub const FIELD_COUNT: usize = 2;
-    pub fn admin_identity(mut self, v: Identity) -> Self {
-        self.admin_identity = v;
-        self
-    }
-    pub fn type_id_hash(mut self, v: Byte32) -> Self {
-        self.type_id_hash = v;
+pub struct WithdrawalExpiryOptBuilder(pub(crate) Option<WithdrawalExpiry>);
+impl WithdrawalExpiryOptBuilder {
+    pub fn set(mut self, v: Option<WithdrawalExpiry>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for CheckpointLockArgsBuilder {
-    type Entity = CheckpointLockArgs;
-    const NAME: &'static str = "CheckpointLockArgsBuilder";
+impl molecule::prelude::Builder for WithdrawalExpiryOptBuilder {
+    type Entity = WithdrawalExpiryOpt;
+    const NAME: &'static str = "WithdrawalExpiryOptBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.admin_identity.as_slice().len()
-            + self.type_id_hash.as_slice().len()
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.admin_identity.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.type_id_hash.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.admin_identity.as_slice())?;
-        writer.write_all(self.type_id_hash.as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        CheckpointLockArgs::new_unchecked(inner.into())
+        WithdrawalExpiryOpt::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -5851,6 +8559,11 @@ impl ::core::fmt::Display for CheckpointLockCellData {
             "withdrawal_lock_code_hash",
             self.withdrawal_lock_code_hash()
         )?;
+        write!(f, ", {}: {}", "reward_schedule", self.reward_schedule())?;
+        write!(f, ", {}: {}", "minted_amount", self.minted_amount())?;
+        write!(f, ", {}: {}", "pending_params", self.pending_params())?;
+        write!(f, ", {}: {}", "reward_mode", self.reward_mode())?;
+        write!(f, ", {}: {}", "withdrawal_expiry", self.withdrawal_expiry())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -5861,21 +8574,25 @@ impl ::core::fmt::Display for CheckpointLockCellData {
 impl ::core::default::Default for CheckpointLockCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            252, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0, 0, 62, 0, 0, 0, 70, 0, 0, 0, 78, 0, 0, 0, 110, 0,
-            0, 0, 114, 0, 0, 0, 118, 0, 0, 0, 122, 0, 0, 0, 138, 0, 0, 0, 146, 0, 0, 0, 156, 0, 0,
-            0, 188, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            102, 1, 0, 0, 80, 0, 0, 0, 81, 0, 0, 0, 82, 0, 0, 0, 90, 0, 0, 0, 98, 0, 0, 0, 130, 0,
+            0, 0, 134, 0, 0, 0, 138, 0, 0, 0, 142, 0, 0, 0, 158, 0, 0, 0, 166, 0, 0, 0, 176, 0, 0,
+            0, 208, 0, 0, 0, 240, 0, 0, 0, 16, 1, 0, 0, 85, 1, 0, 0, 101, 1, 0, 0, 101, 1, 0, 0,
+            102, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 20,
+            0, 0, 0, 21, 0, 0, 0, 37, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         CheckpointLockCellData::new_unchecked(v.into())
     }
 }
 impl CheckpointLockCellData {
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 19;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -5973,11 +8690,41 @@ impl CheckpointLockCellData {
     pub fn withdrawal_lock_code_hash(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_schedule(&self) -> RewardSchedule {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        RewardSchedule::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn minted_amount(&self) -> Byte16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Byte16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pending_params(&self) -> CheckpointParamsOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        CheckpointParamsOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
+        let end = molecule::unpack_number(&slice[76..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn withdrawal_expiry(&self) -> WithdrawalExpiryOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[76..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[80..]) as usize;
+            WithdrawalExpiryOpt::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            WithdrawalExpiryOpt::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> CheckpointLockCellDataReader<'r> {
@@ -6021,6 +8768,11 @@ impl molecule::prelude::Entity for CheckpointLockCellData {
             .sudt_type_hash(self.sudt_type_hash())
             .stake_type_hash(self.stake_type_hash())
             .withdrawal_lock_code_hash(self.withdrawal_lock_code_hash())
+            .reward_schedule(self.reward_schedule())
+            .minted_amount(self.minted_amount())
+            .pending_params(self.pending_params())
+            .reward_mode(self.reward_mode())
+            .withdrawal_expiry(self.withdrawal_expiry())
     }
 }
 #[derive(Clone, Copy)]
@@ -6061,6 +8813,11 @@ impl<'r> ::core::fmt::Display for CheckpointLockCellDataReader<'r> {
             "withdrawal_lock_code_hash",
             self.withdrawal_lock_code_hash()
         )?;
+        write!(f, ", {}: {}", "reward_schedule", self.reward_schedule())?;
+        write!(f, ", {}: {}", "minted_amount", self.minted_amount())?;
+        write!(f, ", {}: {}", "pending_params", self.pending_params())?;
+        write!(f, ", {}: {}", "reward_mode", self.reward_mode())?;
+        write!(f, ", {}: {}", "withdrawal_expiry", self.withdrawal_expiry())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6069,7 +8826,7 @@ impl<'r> ::core::fmt::Display for CheckpointLockCellDataReader<'r> {
     }
 }
 impl<'r> CheckpointLockCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 19;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6167,11 +8924,41 @@ impl<'r> CheckpointLockCellDataReader<'r> {
     pub fn withdrawal_lock_code_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_schedule(&self) -> RewardScheduleReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        RewardScheduleReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn minted_amount(&self) -> Byte16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pending_params(&self) -> CheckpointParamsOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        CheckpointParamsOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
+        let end = molecule::unpack_number(&slice[76..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn withdrawal_expiry(&self) -> WithdrawalExpiryOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[76..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[80..]) as usize;
+            WithdrawalExpiryOptReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            WithdrawalExpiryOptReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -6238,6 +9025,11 @@ impl<'r> molecule::prelude::Reader<'r> for CheckpointLockCellDataReader<'r> {
         Byte32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
         Byte32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
         Byte32Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        RewardScheduleReader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Byte16Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
+        CheckpointParamsOptReader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        ByteReader::verify(&slice[offsets[17]..offsets[18]], compatible)?;
+        WithdrawalExpiryOptReader::verify(&slice[offsets[18]..offsets[19]], compatible)?;
         Ok(())
     }
 }
@@ -6257,9 +9049,14 @@ pub struct CheckpointLockCellDataBuilder {
     pub(crate) sudt_type_hash: Byte32,
     pub(crate) stake_type_hash: Byte32,
     pub(crate) withdrawal_lock_code_hash: Byte32,
+    pub(crate) reward_schedule: RewardSchedule,
+    pub(crate) minted_amount: Byte16,
+    pub(crate) pending_params: CheckpointParamsOpt,
+    pub(crate) reward_mode: Byte,
+    pub(crate) withdrawal_expiry: WithdrawalExpiryOpt,
 }
 impl CheckpointLockCellDataBuilder {
-    pub const FIELD_COUNT: usize = 14;
+    pub const FIELD_COUNT: usize = 19;
     pub fn version(mut self, v: Byte) -> Self {
         self.version = v;
         self
@@ -6316,6 +9113,26 @@ impl CheckpointLockCellDataBuilder {
         self.withdrawal_lock_code_hash = v;
         self
     }
+    pub fn reward_schedule(mut self, v: RewardSchedule) -> Self {
+        self.reward_schedule = v;
+        self
+    }
+    pub fn minted_amount(mut self, v: Byte16) -> Self {
+        self.minted_amount = v;
+        self
+    }
+    pub fn pending_params(mut self, v: CheckpointParamsOpt) -> Self {
+        self.pending_params = v;
+        self
+    }
+    pub fn reward_mode(mut self, v: Byte) -> Self {
+        self.reward_mode = v;
+        self
+    }
+    pub fn withdrawal_expiry(mut self, v: WithdrawalExpiryOpt) -> Self {
+        self.withdrawal_expiry = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for CheckpointLockCellDataBuilder {
     type Entity = CheckpointLockCellData;
@@ -6336,6 +9153,11 @@ impl molecule::prelude::Builder for CheckpointLockCellDataBuilder {
             + self.sudt_type_hash.as_slice().len()
             + self.stake_type_hash.as_slice().len()
             + self.withdrawal_lock_code_hash.as_slice().len()
+            + self.reward_schedule.as_slice().len()
+            + self.minted_amount.as_slice().len()
+            + self.pending_params.as_slice().len()
+            + self.reward_mode.as_slice().len()
+            + self.withdrawal_expiry.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -6368,6 +9190,16 @@ impl molecule::prelude::Builder for CheckpointLockCellDataBuilder {
         total_size += self.stake_type_hash.as_slice().len();
         offsets.push(total_size);
         total_size += self.withdrawal_lock_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_schedule.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.minted_amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pending_params.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_mode.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.withdrawal_expiry.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -6386,6 +9218,11 @@ impl molecule::prelude::Builder for CheckpointLockCellDataBuilder {
         writer.write_all(self.sudt_type_hash.as_slice())?;
         writer.write_all(self.stake_type_hash.as_slice())?;
         writer.write_all(self.withdrawal_lock_code_hash.as_slice())?;
+        writer.write_all(self.reward_schedule.as_slice())?;
+        writer.write_all(self.minted_amount.as_slice())?;
+        writer.write_all(self.pending_params.as_slice())?;
+        writer.write_all(self.reward_mode.as_slice())?;
+        writer.write_all(self.withdrawal_expiry.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -6422,6 +9259,7 @@ impl ::core::fmt::Display for WithdrawalLockArgs {
             self.checkpoint_cell_type_hash()
         )?;
         write!(f, ", {}: {}", "node_identity", self.node_identity())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6432,15 +9270,15 @@ impl ::core::fmt::Display for WithdrawalLockArgs {
 impl ::core::default::Default for WithdrawalLockArgs {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            81, 0, 0, 0, 16, 0, 0, 0, 49, 0, 0, 0, 81, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0,
+            85, 0, 0, 0, 20, 0, 0, 0, 53, 0, 0, 0, 85, 0, 0, 0, 85, 0, 0, 0, 33, 0, 0, 0, 12, 0, 0,
+            0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         WithdrawalLockArgs::new_unchecked(v.into())
     }
 }
 impl WithdrawalLockArgs {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6472,11 +9310,17 @@ impl WithdrawalLockArgs {
     pub fn node_identity(&self) -> IdentityOpt {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        IdentityOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn expiry(&self) -> WithdrawalExpiryOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            IdentityOpt::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            WithdrawalExpiryOpt::new_unchecked(self.0.slice(start..end))
         } else {
-            IdentityOpt::new_unchecked(self.0.slice(start..))
+            WithdrawalExpiryOpt::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> WithdrawalLockArgsReader<'r> {
@@ -6509,6 +9353,7 @@ impl molecule::prelude::Entity for WithdrawalLockArgs {
             .admin_identity(self.admin_identity())
             .checkpoint_cell_type_hash(self.checkpoint_cell_type_hash())
             .node_identity(self.node_identity())
+            .expiry(self.expiry())
     }
 }
 #[derive(Clone, Copy)]
@@ -6538,6 +9383,7 @@ impl<'r> ::core::fmt::Display for WithdrawalLockArgsReader<'r> {
             self.checkpoint_cell_type_hash()
         )?;
         write!(f, ", {}: {}", "node_identity", self.node_identity())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -6546,7 +9392,7 @@ impl<'r> ::core::fmt::Display for WithdrawalLockArgsReader<'r> {
     }
 }
 impl<'r> WithdrawalLockArgsReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -6578,11 +9424,17 @@ impl<'r> WithdrawalLockArgsReader<'r> {
     pub fn node_identity(&self) -> IdentityOptReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        IdentityOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn expiry(&self) -> WithdrawalExpiryOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            IdentityOptReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            WithdrawalExpiryOptReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            IdentityOptReader::new_unchecked(&self.as_slice()[start..])
+            WithdrawalExpiryOptReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -6638,6 +9490,7 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawalLockArgsReader<'r> {
         IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         IdentityOptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        WithdrawalExpiryOptReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
@@ -6646,9 +9499,10 @@ pub struct WithdrawalLockArgsBuilder {
     pub(crate) admin_identity: Identity,
     pub(crate) checkpoint_cell_type_hash: Byte32,
     pub(crate) node_identity: IdentityOpt,
+    pub(crate) expiry: WithdrawalExpiryOpt,
 }
 impl WithdrawalLockArgsBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn admin_identity(mut self, v: Identity) -> Self {
         self.admin_identity = v;
         self
@@ -6661,6 +9515,10 @@ impl WithdrawalLockArgsBuilder {
         self.node_identity = v;
         self
     }
+    pub fn expiry(mut self, v: WithdrawalExpiryOpt) -> Self {
+        self.expiry = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for WithdrawalLockArgsBuilder {
     type Entity = WithdrawalLockArgs;
@@ -6670,6 +9528,7 @@ impl molecule::prelude::Builder for WithdrawalLockArgsBuilder {
             + self.admin_identity.as_slice().len()
             + self.checkpoint_cell_type_hash.as_slice().len()
             + self.node_identity.as_slice().len()
+            + self.expiry.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -6680,6 +9539,8 @@ impl molecule::prelude::Builder for WithdrawalLockArgsBuilder {
         total_size += self.checkpoint_cell_type_hash.as_slice().len();
         offsets.push(total_size);
         total_size += self.node_identity.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.expiry.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -6687,6 +9548,7 @@ impl molecule::prelude::Builder for WithdrawalLockArgsBuilder {
         writer.write_all(self.admin_identity.as_slice())?;
         writer.write_all(self.checkpoint_cell_type_hash.as_slice())?;
         writer.write_all(self.node_identity.as_slice())?;
+        writer.write_all(self.expiry.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -6717,55 +9579,26 @@ impl ::core::fmt::Display for WithdrawalLockCellData {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "amount", self.amount())?;
         write!(f, ", {}: {}", "period", self.period())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
         write!(f, " }}")
     }
 }
 impl ::core::default::Default for WithdrawalLockCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            36, 0, 0, 0, 12, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         WithdrawalLockCellData::new_unchecked(v.into())
     }
 }
 impl WithdrawalLockCellData {
+    pub const TOTAL_SIZE: usize = 24;
+    pub const FIELD_SIZES: [usize; 2] = [16, 8];
     pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
     pub fn amount(&self) -> Byte16 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte16::new_unchecked(self.0.slice(start..end))
+        Byte16::new_unchecked(self.0.slice(0..16))
     }
     pub fn period(&self) -> Byte8 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte8::new_unchecked(self.0.slice(start..end))
-        } else {
-            Byte8::new_unchecked(self.0.slice(start..))
-        }
+        Byte8::new_unchecked(self.0.slice(16..24))
     }
     pub fn as_reader<'r>(&'r self) -> WithdrawalLockCellDataReader<'r> {
         WithdrawalLockCellDataReader::new_unchecked(self.as_slice())
@@ -6819,46 +9652,18 @@ impl<'r> ::core::fmt::Display for WithdrawalLockCellDataReader<'r> {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "amount", self.amount())?;
         write!(f, ", {}: {}", "period", self.period())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
-        }
         write!(f, " }}")
     }
 }
 impl<'r> WithdrawalLockCellDataReader<'r> {
+    pub const TOTAL_SIZE: usize = 24;
+    pub const FIELD_SIZES: [usize; 2] = [16, 8];
     pub const FIELD_COUNT: usize = 2;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
     pub fn amount(&self) -> Byte16Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte16Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte16Reader::new_unchecked(&self.as_slice()[0..16])
     }
     pub fn period(&self) -> Byte8Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte8Reader::new_unchecked(&self.as_slice()[start..end])
-        } else {
-            Byte8Reader::new_unchecked(&self.as_slice()[start..])
-        }
+        Byte8Reader::new_unchecked(&self.as_slice()[16..24])
     }
 }
 impl<'r> molecule::prelude::Reader<'r> for WithdrawalLockCellDataReader<'r> {
@@ -6873,45 +9678,12 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawalLockCellDataReader<'r> {
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let total_size = molecule::unpack_number(slice) as usize;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
-        }
-        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
-            return Ok(());
-        }
-        if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
-        }
-        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
-        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
-            return ve!(Self, OffsetsNotMatch);
-        }
-        if slice_len < offset_first {
-            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
-        }
-        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-        if field_count < Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        } else if !compatible && field_count > Self::FIELD_COUNT {
-            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
-        };
-        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
-            .chunks_exact(molecule::NUMBER_SIZE)
-            .map(|x| molecule::unpack_number(x) as usize)
-            .collect();
-        offsets.push(total_size);
-        if offsets.windows(2).any(|i| i[0] > i[1]) {
-            return ve!(Self, OffsetsNotMatch);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
-        Byte16Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte8Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
@@ -6921,6 +9693,8 @@ pub struct WithdrawalLockCellDataBuilder {
     pub(crate) period: Byte8,
 }
 impl WithdrawalLockCellDataBuilder {
+    pub const TOTAL_SIZE: usize = 24;
+    pub const FIELD_SIZES: [usize; 2] = [16, 8];
     pub const FIELD_COUNT: usize = 2;
     pub fn amount(mut self, v: Byte16) -> Self {
         self.amount = v;
@@ -6935,21 +9709,9 @@ impl molecule::prelude::Builder for WithdrawalLockCellDataBuilder {
     type Entity = WithdrawalLockCellData;
     const NAME: &'static str = "WithdrawalLockCellDataBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.amount.as_slice().len()
-            + self.period.as_slice().len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
-        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
-        offsets.push(total_size);
-        total_size += self.amount.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.period.as_slice().len();
-        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-        for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
         writer.write_all(self.amount.as_slice())?;
         writer.write_all(self.period.as_slice())?;
         Ok(())
@@ -1,16 +1,198 @@
 #![allow(dead_code)]
 
-use crate::axon;
+use crate::{axon, Loader};
+use bls12_381::{
+    hash_to_curve::{ExpandMsgXmd, HashToCurve},
+    G1Affine, G2Affine, G2Projective, Scalar,
+};
+use ckb_system_scripts::BUNDLED_CELL;
 use ckb_testtool::ckb_crypto::secp::{Privkey, Pubkey};
 use ckb_testtool::ckb_hash::{blake2b_256, new_blake2b};
 use ckb_testtool::ckb_types::{
     bytes::Bytes,
-    core::TransactionView,
+    core::{TransactionBuilder, TransactionView},
     packed::{self, *},
     prelude::*,
     H256,
 };
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use molecule::prelude::*;
+use tiny_keccak::{Hasher, Keccak};
+
+// cells which transactions under test depend on, which are the contract under test referenced by
+// the hash of its code, the always_success contract and the secp256k1 data
+pub struct Deployment {
+    contract_out_point: OutPoint,
+    always_success_out_point: OutPoint,
+    cell_deps: Vec<CellDep>,
+}
+
+impl Deployment {
+    pub fn new(context: &mut Context, name: &str) -> Self {
+        let contract_out_point = context.deploy_cell(Loader::default().load_binary(name));
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+        let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+        let cell_deps = vec![
+            contract_out_point.clone(),
+            always_success_out_point.clone(),
+            secp256k1_data_out_point,
+        ]
+        .into_iter()
+        .map(|out_point| CellDep::new_builder().out_point(out_point).build())
+        .collect();
+        Deployment {
+            contract_out_point,
+            always_success_out_point,
+            cell_deps,
+        }
+    }
+
+    pub fn script(&self, context: &mut Context, args: Bytes) -> Script {
+        context
+            .build_script(&self.contract_out_point, args)
+            .expect("contract script")
+    }
+
+    // always_success scripts are told apart by their args
+    pub fn always_success_script(&self, context: &mut Context, args: &[u8]) -> Script {
+        context
+            .build_script(&self.always_success_out_point, Bytes::from(args.to_vec()))
+            .expect("always_success script")
+    }
+
+    // deploy the contract of `name` into a cell typed by the always_success script of
+    // `type_args`, and return the type hash of its code, by which its scripts are referenced
+    pub fn deploy_by_type(
+        &mut self,
+        context: &mut Context,
+        name: &str,
+        type_args: &[u8],
+    ) -> Byte32 {
+        let code_type_script = self.always_success_script(context, type_args);
+        self.add_cell_dep(
+            context,
+            &code_type_script,
+            Loader::default().load_binary(name),
+        );
+        code_type_script.calc_script_hash()
+    }
+
+    // create a cell typed by `type_script` with `data`, such as a checkpoint or stake cell, and
+    // depend on it
+    pub fn add_cell_dep(&mut self, context: &mut Context, type_script: &Script, data: Bytes) {
+        let lock_script = self.always_success_script(context, &[1]);
+        let out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(lock_script)
+                .type_(Some(type_script.clone()).pack())
+                .build(),
+            data,
+        );
+        self.cell_deps
+            .push(CellDep::new_builder().out_point(out_point).build());
+    }
+
+    // transaction builder depending on every deployed cell
+    pub fn tx_builder(&self) -> TransactionBuilder {
+        TransactionBuilder::default().cell_deps(self.cell_deps.clone())
+    }
+}
+
+// input spending a new cell of `output` with `data`
+pub fn create_input(context: &mut Context, output: &CellOutput, data: Bytes) -> CellInput {
+    CellInput::new_builder()
+        .previous_output(context.create_cell(output.clone(), data))
+        .build()
+}
+
+// cell of 1000 CKB under `lock`, typed by `type_script` if it is given
+pub fn cell_output(lock: &Script, type_script: Option<&Script>) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(lock.clone())
+        .type_(type_script.cloned().pack())
+        .build()
+}
+
+// transaction under test, to which each test adds the cells it spends and creates and the
+// witnesses of their lock groups one by one, so every cell a case is about is spelled out in it
+#[derive(Default)]
+pub struct TestTx {
+    inputs: Vec<CellInput>,
+    outputs: Vec<CellOutput>,
+    outputs_data: Vec<Bytes>,
+    witnesses: Vec<Bytes>,
+}
+
+impl TestTx {
+    // spend a new cell of `output` with `data`
+    pub fn input(self, context: &mut Context, output: &CellOutput, data: impl Into<Bytes>) -> Self {
+        self.input_since(context, output, data, 0)
+    }
+
+    pub fn input_since(
+        mut self,
+        context: &mut Context,
+        output: &CellOutput,
+        data: impl Into<Bytes>,
+        since: u64,
+    ) -> Self {
+        let input = create_input(context, output, data.into())
+            .as_builder()
+            .since(since.pack())
+            .build();
+        self.inputs.push(input);
+        self
+    }
+
+    pub fn output(mut self, output: &CellOutput, data: impl Into<Bytes>) -> Self {
+        self.outputs.push(output.clone());
+        self.outputs_data.push(data.into());
+        self
+    }
+
+    // witness at `index`, and the witnesses before it are left empty
+    pub fn witness(mut self, index: usize, witness: WitnessArgs) -> Self {
+        if self.witnesses.len() <= index {
+            self.witnesses.resize(index + 1, Bytes::new());
+        }
+        self.witnesses[index] = witness.as_bytes();
+        self
+    }
+
+    // transaction depending on every cell of `deployment`
+    pub fn build(self, deployment: &Deployment) -> TransactionView {
+        deployment
+            .tx_builder()
+            .inputs(self.inputs)
+            .outputs(self.outputs)
+            .outputs_data(self.outputs_data.pack())
+            .witnesses(self.witnesses.into_iter().map(|witness| witness.pack()))
+            .build()
+    }
+}
+
+// witness which selects the contract mode by `input_type`
+pub fn input_type_witness(input_type: &[u8]) -> WitnessArgs {
+    WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(input_type.to_vec())).pack())
+        .build()
+}
+
+// data of a sUDT cell holding `amount`
+pub fn sudt_data(amount: u128) -> Vec<u8> {
+    amount.to_le_bytes().to_vec()
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(data);
+    keccak.finalize(&mut hash);
+    hash
+}
 
 pub fn blake160(data: &[u8]) -> [u8; 20] {
     let mut buf = [0u8; 20];
@@ -24,10 +206,22 @@ pub fn axon_byte32(bytes: &Byte32) -> axon::Byte32 {
     axon::Byte32::new_unchecked(bytes.to_vec().into())
 }
 
+pub fn axon_hash(bytes: &[u8; 32]) -> axon::Byte32 {
+    axon::Byte32::new_unchecked(bytes.to_vec().into())
+}
+
 pub fn axon_byte20(bytes: &[u8; 20]) -> axon::Byte20 {
     axon::Byte20::new_unchecked(bytes.to_vec().into())
 }
 
+pub fn axon_byte16(value: u128) -> axon::Byte16 {
+    axon::Byte16::new_unchecked(value.to_le_bytes().to_vec().into())
+}
+
+pub fn axon_byte4(value: u32) -> axon::Byte4 {
+    axon::Byte4::new_unchecked(value.to_le_bytes().to_vec().into())
+}
+
 pub fn axon_byte8(value: u64) -> axon::Byte8 {
     axon::Byte8::new_unchecked(value.to_le_bytes().to_vec().into())
 }
@@ -60,16 +254,22 @@ pub fn axon_checkpoint_data(
 }
 
 pub fn axon_withdrawal_data(period: u64) -> Vec<u8> {
-    let mut data = vec![];
-    data.append(&mut 0u128.to_le_bytes().to_vec());
-    data.append(&mut period.to_le_bytes().to_vec());
-    data
+    axon_withdrawal_amount_data(0, period)
+}
+
+pub fn axon_withdrawal_amount_data(amount: u128, period: u64) -> Vec<u8> {
+    axon::WithdrawalLockCellData::new_builder()
+        .amount(axon_byte16(amount))
+        .period(axon_byte8(period))
+        .build()
+        .as_bytes()
+        .to_vec()
 }
 
 pub fn axon_stake_info(pubkey_hash: &[u8; 20], era: u64) -> axon::StakeInfo {
     let identity = axon::Identity::new_builder()
         .flag(Byte::from(0))
-        .content(axon_byte20(&pubkey_hash))
+        .content(axon_byte20(pubkey_hash))
         .build();
     axon::StakeInfo::new_builder()
         .identity(identity)
@@ -77,6 +277,21 @@ pub fn axon_stake_info(pubkey_hash: &[u8; 20], era: u64) -> axon::StakeInfo {
         .build()
 }
 
+pub fn axon_validator_info(
+    pubkey_hash: &[u8; 20],
+    l2_address: &[u8; 20],
+    bls_pub_key: &[u8],
+    stake_amount: u128,
+    era: u64,
+) -> axon::StakeInfo {
+    axon_stake_info(pubkey_hash, era)
+        .as_builder()
+        .l2_address(axon_byte20(l2_address))
+        .bls_pub_key(axon::Byte97::new_unchecked(bls_pub_key.to_vec().into()))
+        .stake_amount(axon_byte16(stake_amount))
+        .build()
+}
+
 pub fn axon_stake_data(
     quorum: u8,
     checkpoint_type_hash: &Byte32,
@@ -91,13 +306,35 @@ pub fn axon_stake_data(
 }
 
 pub fn sign_tx(tx: TransactionView, key: &Privkey, mode: u8) -> TransactionView {
-    let mut signed_witnesses: Vec<packed::Bytes> = Vec::new();
+    sign_tx_with_input_type(tx, key, vec![mode])
+}
+
+pub fn sign_tx_with_input_type(
+    tx: TransactionView,
+    key: &Privkey,
+    input_type: Vec<u8>,
+) -> TransactionView {
+    sign_tx_at(tx, key, 0, input_type)
+}
+
+// sign the witness of the lock group whose only input is at `index`, or whose other witnesses
+// are empty, and keep the rest of witnesses
+pub fn sign_tx_at(
+    tx: TransactionView,
+    key: &Privkey,
+    index: usize,
+    input_type: Vec<u8>,
+) -> TransactionView {
+    let mut witnesses: Vec<packed::Bytes> = tx.witnesses().into_iter().collect();
+    if witnesses.len() <= index {
+        witnesses.resize(index + 1, packed::Bytes::default());
+    }
     let mut blake2b = new_blake2b();
     blake2b.update(&tx.hash().raw_data());
-    // digest the first witness
+    // digest the first witness of the group
     let witness = WitnessArgs::new_builder()
         .lock(Some(Bytes::from(vec![0u8; 65])).pack())
-        .input_type(Some(Bytes::from(vec![mode])).pack())
+        .input_type(Some(Bytes::from(input_type)).pack())
         .build();
     let witness_size = witness.as_bytes().len() as u64;
     let mut message = [0u8; 32];
@@ -106,15 +343,137 @@ pub fn sign_tx(tx: TransactionView, key: &Privkey, mode: u8) -> TransactionView
     blake2b.finalize(&mut message);
     let message = H256::from(message);
     let sig = key.sign_recoverable(&message).expect("sign");
-    signed_witnesses.push(
-        witness
-            .as_builder()
-            .lock(Some(Bytes::from(sig.serialize())).pack())
-            .build()
-            .as_bytes()
-            .pack(),
-    );
-    tx.as_advanced_builder()
-        .set_witnesses(signed_witnesses)
+    witnesses[index] = witness
+        .as_builder()
+        .lock(Some(Bytes::from(sig.serialize())).pack())
         .build()
+        .as_bytes()
+        .pack();
+    tx.as_advanced_builder().set_witnesses(witnesses).build()
+}
+
+fn rlp_header(size: usize, offset: u8) -> Vec<u8> {
+    if size < 56 {
+        return vec![offset + size as u8];
+    }
+    let size_bytes = size
+        .to_be_bytes()
+        .iter()
+        .skip_while(|byte| **byte == 0)
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut header = vec![offset + 55 + size_bytes.len() as u8];
+    header.extend(size_bytes);
+    header
+}
+
+pub fn rlp_bytes(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        return data.to_vec();
+    }
+    let mut item = rlp_header(data.len(), 0x80);
+    item.extend_from_slice(data);
+    item
+}
+
+pub fn rlp_u64(value: u64) -> Vec<u8> {
+    let bytes = value
+        .to_be_bytes()
+        .iter()
+        .skip_while(|byte| **byte == 0)
+        .cloned()
+        .collect::<Vec<_>>();
+    rlp_bytes(&bytes)
+}
+
+pub fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut item = rlp_header(payload.len(), 0xc0);
+    item.extend(payload);
+    item
+}
+
+// RLP encoded Axon block header, whose fields not checked by contracts are left empty
+pub fn axon_header(
+    proposer: &[u8; 20],
+    number: u64,
+    last_checkpoint_block_hash: &[u8; 32],
+) -> Vec<u8> {
+    let mut fields = vec![rlp_bytes(&[0u8; 32]); 19];
+    fields[1] = rlp_bytes(proposer);
+    fields[6] = rlp_bytes(&[0u8; 256]);
+    fields[9] = rlp_u64(number);
+    fields[14] = rlp_bytes(&[0u8; 8]);
+    fields[16] = rlp_list(&[]);
+    fields[17] = rlp_bytes(last_checkpoint_block_hash);
+    fields[18] = rlp_u64(1);
+    rlp_list(&fields)
+}
+
+// RLP encoded L2 checkpoint of `header`, which is proved by its consensus proof
+pub fn axon_checkpoint(
+    header: &[u8],
+    number: u64,
+    block_hash: &[u8; 32],
+    signature: &[u8],
+    bitmap: &[u8],
+) -> Vec<u8> {
+    let proof = rlp_list(&[
+        rlp_u64(number),
+        rlp_u64(0),
+        rlp_bytes(block_hash),
+        rlp_bytes(signature),
+        rlp_bytes(bitmap),
+    ]);
+    rlp_list(&[header.to_vec(), proof])
+}
+
+// BLS secret key and its public key, which is the uncompressed G1 point prefixed with 0x04
+pub fn bls_keypair(seed: u64) -> (Scalar, Vec<u8>) {
+    let secret_key = Scalar::from(seed);
+    let mut pub_key = vec![0x04];
+    pub_key
+        .extend_from_slice(&G1Affine::from(G1Affine::generator() * secret_key).to_uncompressed());
+    (secret_key, pub_key)
+}
+
+fn bls_hash_to_curve(message: &[u8], dst: &[u8]) -> G2Projective {
+    <G2Projective as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::hash_to_curve(message, dst)
+}
+
+// aggregated signature of `message` signed by all of `secret_keys`
+pub fn bls_aggregate_sign(secret_keys: &[Scalar], message: &[u8]) -> Vec<u8> {
+    let hashed_message = bls_hash_to_curve(message, b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_");
+    let signature = secret_keys
+        .iter()
+        .fold(G2Projective::identity(), |signature, secret_key| {
+            signature + hashed_message * secret_key
+        });
+    G2Affine::from(signature).to_compressed().to_vec()
+}
+
+fn bls_g1_point(pub_key: &[u8]) -> G1Affine {
+    let mut uncompressed = [0u8; 96];
+    uncompressed.copy_from_slice(&pub_key[1..]);
+    G1Affine::from_uncompressed(&uncompressed).unwrap()
+}
+
+// proof of possession of `pub_key`, which is the signature of its compressed point
+pub fn bls_proof_of_possession(secret_key: &Scalar, pub_key: &[u8]) -> Vec<u8> {
+    let hashed_pub_key = bls_hash_to_curve(
+        &bls_g1_point(pub_key).to_compressed(),
+        b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_",
+    );
+    G2Affine::from(hashed_pub_key * secret_key)
+        .to_compressed()
+        .to_vec()
+}
+
+// rogue public key which cancels `victim_pub_key` out, so the aggregated public key of both is
+// the public key of `secret_key`
+pub fn bls_rogue_pub_key(secret_key: &Scalar, victim_pub_key: &[u8]) -> Vec<u8> {
+    let rogue_pub_key = G1Affine::generator() * secret_key - bls_g1_point(victim_pub_key);
+    let mut pub_key = vec![0x04];
+    pub_key.extend_from_slice(&G1Affine::from(rogue_pub_key).to_uncompressed());
+    pub_key
 }
@@ -4,6 +4,7 @@ use std::fs;
 use std::path::PathBuf;
 use std::str::FromStr;
 
+#[allow(clippy::all)]
 mod axon;
 mod helper;
 #[cfg(test)]
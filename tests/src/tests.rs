@@ -1,13 +1,79 @@
 use super::*;
 use ckb_system_scripts::BUNDLED_CELL;
-use ckb_testtool::ckb_crypto::secp::Generator;
-use ckb_testtool::ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*};
+use ckb_testtool::ckb_crypto::secp::{Generator, Privkey, Pubkey};
+use ckb_testtool::ckb_error::Error;
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{ScriptHashType, TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
 use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use helper::*;
 use molecule::prelude::*;
 
 const MAX_CYCLES: u64 = 10_000_000;
 
+// verifying BLS signatures takes far more cycles, so it is bounded by max_block_cycles
+const MAX_BLS_CYCLES: u64 = 3_500_000_000;
+
+// error codes of selection lock
+const ERROR_OMNI_CHECKPOINT_COUNT: i8 = 5;
+const ERROR_CHECKPOINT_LOCK_MISSING: i8 = 9;
+const ERROR_SELECTION_CELL_NOT_KEPT: i8 = 11;
+const ERROR_BAD_RECOVERY_SINCE: i8 = 14;
+
+// error codes of checkpoint lock
+const ERROR_CHECKPOINT_DATA_MISMATCH: i8 = 7;
+const ERROR_AT_AMOUNT_MISMATCH: i8 = 12;
+const ERROR_CHECKPOINT_RLP_TRUNCATED: i8 = 14;
+const ERROR_CHECKPOINT_RLP_TRAILING_BYTES: i8 = 20;
+const ERROR_BLOCK_HASH_MISMATCH: i8 = 21;
+const ERROR_BITMAP_OUT_OF_RANGE: i8 = 24;
+const ERROR_BLS_SIGNATURE_MISMATCH: i8 = 25;
+const ERROR_QUORUM_NOT_REACHED: i8 = 26;
+const ERROR_BAD_CHECKPOINT_PERIOD: i8 = 28;
+const ERROR_BAD_CHECKPOINT_ERA: i8 = 29;
+const ERROR_BAD_CHECKPOINT_BLOCK_NUMBER: i8 = 31;
+const ERROR_BAD_WITHDRAWAL_PERIOD: i8 = 35;
+const ERROR_WITHDRAWAL_AMOUNT_MISMATCH: i8 = 36;
+const ERROR_BAD_REWARD_SCHEDULE: i8 = 38;
+const ERROR_MINTED_AMOUNT_MISMATCH: i8 = 39;
+const ERROR_BAD_EFFECTIVE_ERA: i8 = 40;
+const ERROR_BAD_PENDING_PARAMS: i8 = 42;
+
+// error codes of withdrawal lock
+const ERROR_WITHDRAWAL_ENCODING: i8 = 4;
+const ERROR_BAD_CHECKPOINT_CELLDEP: i8 = 11;
+const ERROR_WITHDRAWAL_BAD_PERIOD: i8 = 13;
+const ERROR_BAD_WITHDRAWAL_TYPE_HASH: i8 = 14;
+const ERROR_SOME_WITHDRAWAL_TYPE_EMPTY: i8 = 15;
+const ERROR_WITHDRAWAL_TOTAL_SUDT_MISMATCH: i8 = 16;
+const ERROR_WITHDRAWAL_BUCKET_MISMATCH: i8 = 19;
+const ERROR_WITHDRAWAL_NOT_EXPIRED: i8 = 21;
+
+// error codes of stake lock
+const ERROR_STAKE_ADMIN_MODE: i8 = 14;
+const ERROR_STAKE_BAD_INAUGURATION_ERA: i8 = 19;
+const ERROR_STAKE_AMOUNT_MISMATCH: i8 = 23;
+const ERROR_STAKE_BAD_WITHDRAWAL_PERIOD: i8 = 25;
+const ERROR_STAKE_WITHDRAWAL_AMOUNT_MISMATCH: i8 = 26;
+const ERROR_BAD_SLASH_PROOF: i8 = 27;
+const ERROR_SLASH_TARGET: i8 = 28;
+const ERROR_SLASHED_AT_NOT_BURNED: i8 = 29;
+const ERROR_BAD_PROOF_OF_POSSESSION: i8 = 31;
+const ERROR_UNBONDING_AT_NOT_SLASHED: i8 = 32;
+
+// check `err` is raised by a script exiting with `code`
+fn assert_script_error(err: Error, code: i8) {
+    let message = err.to_string();
+    assert!(
+        message.contains(&format!("error code {} in", code)),
+        "unexpected error: {}",
+        message
+    );
+}
+
 #[test]
 fn test_selection_success() {
     // deploy contract
@@ -95,6 +161,347 @@ fn test_selection_success() {
     println!("consume cycles: {}", cycles);
 }
 
+// selection cell spent together with cells under always_success locks, and the lock of args [0]
+// is the owner, the lock of args [1] is the checkpoint and the lock of args [3] is the recovery
+struct SelectionScene {
+    deployment: Deployment,
+}
+
+impl SelectionScene {
+    fn new(context: &mut Context) -> Self {
+        SelectionScene {
+            deployment: Deployment::new(context, "selection"),
+        }
+    }
+
+    // cell of the always_success lock of `args`
+    fn lock_output(&self, context: &mut Context, args: u8) -> CellOutput {
+        cell_output(
+            &self.deployment.always_success_script(context, &[args]),
+            None,
+        )
+    }
+
+    // selection cell authorizing (role, args) of `authorized_locks` besides owner and
+    // checkpoint, which is recovered after `recovery` epochs if it is given
+    fn selection_output(
+        &self,
+        context: &mut Context,
+        authorized_locks: &[(u8, u8)],
+        recovery: Option<u64>,
+    ) -> CellOutput {
+        let lock_hash = |context: &mut Context, args: u8| {
+            axon_byte32(
+                &self
+                    .deployment
+                    .always_success_script(context, &[args])
+                    .calc_script_hash(),
+            )
+        };
+        let authorized_locks = authorized_locks
+            .iter()
+            .map(|(role, args)| {
+                axon::AuthorizedLock::new_builder()
+                    .role(Byte::from(*role))
+                    .lock_hash(lock_hash(context, *args))
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let recovery = recovery.map(|since_epochs| {
+            axon::SelectionRecovery::new_builder()
+                .recovery_lock_hash(lock_hash(context, 3))
+                .since_epochs(axon_byte8(since_epochs))
+                .build()
+        });
+        let selection_args = axon::SelectionLockArgs::new_builder()
+            .omni_lock_hash(lock_hash(context, 0))
+            .checkpoint_lock_hash(lock_hash(context, 1))
+            .authorized_locks(
+                axon::AuthorizedLockVec::new_builder()
+                    .set(authorized_locks)
+                    .build(),
+            )
+            .recovery(
+                axon::SelectionRecoveryOpt::new_builder()
+                    .set(recovery)
+                    .build(),
+            )
+            .build();
+        cell_output(
+            &self.deployment.script(context, selection_args.as_bytes()),
+            None,
+        )
+    }
+}
+
+// relative since in epoch of `epochs` epochs
+fn relative_epoch_since(epochs: u64) -> u64 {
+    0xa000_0000_0000_0000 | epochs
+}
+
+#[test]
+fn test_selection_emergency_role() {
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[(2, 2)], None);
+    let emergency_output = scene.lock_output(&mut context, 2);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &emergency_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&emergency_output, Bytes::new())
+        .witness(0, input_type_witness(&[2]))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_selection_role_of_shared_lock() {
+    // owner lock is also authorized as emergency, and acts as emergency named in witness
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[(2, 0)], None);
+    let owner_output = scene.lock_output(&mut context, 0);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &owner_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&owner_output, Bytes::new())
+        .witness(0, input_type_witness(&[2]))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_selection_cells_of_same_lock() {
+    // two cells of owner lock are two authorized cells, while exactly one is allowed
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let owner_output = scene.lock_output(&mut context, 0);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &owner_output, Bytes::new())
+        .input(&mut context, &owner_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&owner_output, Bytes::new())
+        .output(&owner_output, Bytes::new())
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_OMNI_CHECKPOINT_COUNT);
+}
+
+#[test]
+fn test_selection_empty_witness() {
+    // the witness of the selection cell is left empty for the witness of owner lock behind it,
+    // and names no role
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let owner_output = scene.lock_output(&mut context, 0);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &owner_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&owner_output, Bytes::new())
+        .witness(1, WitnessArgs::default())
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_selection_role_missing() {
+    // witness names checkpoint while only owner lock is present
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let owner_output = scene.lock_output(&mut context, 0);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &owner_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&owner_output, Bytes::new())
+        .witness(0, input_type_witness(&[1]))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_CHECKPOINT_LOCK_MISSING);
+}
+
+#[test]
+fn test_selection_checkpoint_keeps_cell() {
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let checkpoint_output = scene.lock_output(&mut context, 1);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &checkpoint_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&checkpoint_output, Bytes::new())
+        .witness(0, input_type_witness(&[1]))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_selection_checkpoint_drains_cell() {
+    // the selection cell is moved to checkpoint lock
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let checkpoint_output = scene.lock_output(&mut context, 1);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &checkpoint_output, Bytes::new())
+        .output(&checkpoint_output, vec![0])
+        .output(&checkpoint_output, Bytes::new())
+        .witness(0, input_type_witness(&[1]))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SELECTION_CELL_NOT_KEPT);
+}
+
+#[test]
+fn test_selection_checkpoint_changes_data() {
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], None);
+    let checkpoint_output = scene.lock_output(&mut context, 1);
+    let tx = TestTx::default()
+        .input(&mut context, &selection_output, vec![0])
+        .input(&mut context, &checkpoint_output, Bytes::new())
+        .output(&selection_output, vec![1])
+        .output(&checkpoint_output, Bytes::new())
+        .witness(0, input_type_witness(&[1]))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SELECTION_CELL_NOT_KEPT);
+}
+
+#[test]
+fn test_selection_recovery_success() {
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], Some(10));
+    let recovery_output = scene.lock_output(&mut context, 3);
+    let tx = TestTx::default()
+        .input_since(
+            &mut context,
+            &selection_output,
+            vec![0],
+            relative_epoch_since(10),
+        )
+        .input(&mut context, &recovery_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&recovery_output, Bytes::new())
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_selection_recovery_since_too_small() {
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], Some(10));
+    let recovery_output = scene.lock_output(&mut context, 3);
+    let tx = TestTx::default()
+        .input_since(
+            &mut context,
+            &selection_output,
+            vec![0],
+            relative_epoch_since(9),
+        )
+        .input(&mut context, &recovery_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&recovery_output, Bytes::new())
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_RECOVERY_SINCE);
+}
+
+#[test]
+fn test_selection_recovery_absolute_since() {
+    // absolute since in epoch 10 says nothing about how long the selection cell has been alive
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], Some(10));
+    let recovery_output = scene.lock_output(&mut context, 3);
+    let tx = TestTx::default()
+        .input_since(
+            &mut context,
+            &selection_output,
+            vec![0],
+            relative_epoch_since(10) & !0x8000_0000_0000_0000,
+        )
+        .input(&mut context, &recovery_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&recovery_output, Bytes::new())
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_RECOVERY_SINCE);
+}
+
+#[test]
+fn test_selection_recovery_since_epochs_overflow() {
+    // since_epochs which does not fit in the epoch number of since
+    let mut context = Context::default();
+    let scene = SelectionScene::new(&mut context);
+    let selection_output = scene.selection_output(&mut context, &[], Some(0x100_0000));
+    let recovery_output = scene.lock_output(&mut context, 3);
+    let tx = TestTx::default()
+        .input_since(
+            &mut context,
+            &selection_output,
+            vec![0],
+            relative_epoch_since(0xff_ffff),
+        )
+        .input(&mut context, &recovery_output, Bytes::new())
+        .output(&selection_output, vec![0])
+        .output(&recovery_output, Bytes::new())
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_RECOVERY_SINCE);
+}
+
 #[test]
 fn test_checkpoint_success() {
     // init context
@@ -216,103 +623,96 @@ fn test_checkpoint_success() {
     println!("consume cycles: {}", cycles);
 }
 
-#[test]
-fn test_withdrawal_success() {
-    // init context
-    let mut context = Context::default();
-    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
-    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
-    let secp256k1_data_dep = CellDep::new_builder()
-        .out_point(secp256k1_data_out_point)
-        .build();
-    let contract_bin: Bytes = Loader::default().load_binary("withdrawal");
-    let contract_out_point = context.deploy_cell(contract_bin);
-    let contract_dep = CellDep::new_builder()
-        .out_point(contract_out_point.clone())
-        .build();
-    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
-    let always_success_lock_script = context
-        .build_script(&always_success_out_point, Bytes::from(vec![1]))
-        .expect("always_success script");
-    let type_id_type_script = context
-        .build_script(&always_success_out_point, Bytes::new())
-        .expect("type_id script");
-    let at_type_script = context
-        .build_script(&always_success_out_point, Bytes::from(vec![1]))
-        .expect("at script");
-    let always_success_script_dep = CellDep::new_builder()
-        .out_point(always_success_out_point)
-        .build();
+// checkpoint cell of admin in era 1, whose params are rescheduled in reconfigure mode
+struct CheckpointReconfigureScene {
+    deployment: Deployment,
+    admin_keypair: (Privkey, Pubkey),
+    checkpoint_output: CellOutput,
+    checkpoint_data: axon::CheckpointLockCellData,
+}
 
-    // prepare checkpoint_args and checkpoint_data
-    let keypair = Generator::random_keypair();
-    let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
-        .admin_identity(axon_identity(&keypair.1))
-        .checkpoint_cell_type_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
-        .node_identity(axon_identity_opt(&keypair.1))
-        .build();
-    let withdrawal_data = axon_withdrawal_data(1);
+impl CheckpointReconfigureScene {
+    fn new(context: &mut Context) -> Self {
+        let deployment = Deployment::new(context, "checkpoint");
+        let type_id_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[1]);
 
-    // prepare checkpoint lock_script
-    let withdrawal_lock_script = context
-        .build_script(&contract_out_point, withdrawal_args.as_bytes())
-        .expect("withdrawal script");
+        // prepare checkpoint cell of admin
+        let admin_keypair = Generator::random_keypair();
+        let checkpoint_args = axon::CheckpointLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+            .build();
+        let checkpoint_output = cell_output(
+            &deployment.script(context, checkpoint_args.as_bytes()),
+            Some(&type_id_type_script),
+        );
+        let checkpoint_data = axon_checkpoint_data(10, 1, &at_type_script.calc_script_hash())
+            .as_builder()
+            .era(axon_byte8(1))
+            .period_interval(axon_byte4(100))
+            .era_period(axon_byte4(10))
+            .unlock_period(axon_byte4(2))
+            .build();
+        CheckpointReconfigureScene {
+            deployment,
+            admin_keypair,
+            checkpoint_output,
+            checkpoint_data,
+        }
+    }
 
-    // prepare tx inputs and outputs
-    let inputs = vec![
-        // withdrawal cell
-        CellInput::new_builder()
-            .previous_output(
-                context.create_cell(
-                    CellOutput::new_builder()
-                        .capacity(1000.pack())
-                        .lock(withdrawal_lock_script.clone())
-                        .type_(Some(at_type_script.clone()).pack())
-                        .build(),
-                    Bytes::from(withdrawal_data.clone()),
-                ),
+    // checkpoint data scheduling `pending_params`
+    fn reconfigured_data(
+        &self,
+        pending_params: axon::CheckpointParams,
+    ) -> axon::CheckpointLockCellData {
+        self.checkpoint_data
+            .clone()
+            .as_builder()
+            .pending_params(
+                axon::CheckpointParamsOpt::new_builder()
+                    .set(Some(pending_params))
+                    .build(),
             )
-            .build(),
-    ];
-    let outputs = vec![
-        // withdrawal cell
-        CellOutput::new_builder()
-            .capacity(1000.pack())
-            .lock(withdrawal_lock_script)
-            .type_(Some(at_type_script).pack())
-            .build(),
-    ];
+            .build()
+    }
 
-    // prepare outputs_data
-    let outputs_data = vec![Bytes::from(withdrawal_data)];
+    // transaction updating the checkpoint cell to `output_data`, signed by admin
+    fn reconfigure_tx(
+        &self,
+        context: &mut Context,
+        output_data: &axon::CheckpointLockCellData,
+    ) -> TransactionView {
+        let tx = TestTx::default()
+            .input(
+                context,
+                &self.checkpoint_output,
+                self.checkpoint_data.as_bytes(),
+            )
+            .output(&self.checkpoint_output, output_data.as_bytes())
+            .build(&self.deployment);
+        sign_tx(tx, &self.admin_keypair.0, 2)
+    }
+}
 
-    // prepare checkpoint cell_dep
-    let checkpoint_data = axon_checkpoint_data(1, 1, &type_id_type_script.calc_script_hash());
-    let checkpoint_script_dep = CellDep::new_builder()
-        .out_point(
-            context.create_cell(
-                CellOutput::new_builder()
-                    .capacity(1000.pack())
-                    .lock(always_success_lock_script)
-                    .type_(Some(type_id_type_script).pack())
-                    .build(),
-                checkpoint_data.as_bytes(),
-            ),
-        )
-        .build();
+// params effective from `effective_era` with unlock_period of 4 and base_reward of 500 AT
+// halved every `half_period` periods
+fn pending_checkpoint_params(effective_era: u64, half_period: u64) -> axon::CheckpointParams {
+    axon::CheckpointParams::new_builder()
+        .effective_era(axon_byte8(effective_era))
+        .unlock_period(axon_byte4(4))
+        .base_reward(axon_byte16(500))
+        .half_period(axon_byte8(half_period))
+        .build()
+}
 
-    // prepare signed tx
-    let tx = TransactionBuilder::default()
-        .inputs(inputs)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .cell_dep(contract_dep)
-        .cell_dep(always_success_script_dep)
-        .cell_dep(secp256k1_data_dep)
-        .cell_dep(checkpoint_script_dep)
-        .build();
-    let tx = context.complete_tx(tx);
-    let tx = sign_tx(tx, &keypair.0, 1);
+#[test]
+fn test_checkpoint_reconfigure_success() {
+    let mut context = Context::default();
+    let scene = CheckpointReconfigureScene::new(&mut context);
+    let output_data = scene.reconfigured_data(pending_checkpoint_params(2, 2));
+    let tx = scene.reconfigure_tx(&mut context, &output_data);
 
     // run
     let cycles = context
@@ -322,103 +722,3051 @@ fn test_withdrawal_success() {
 }
 
 #[test]
-fn test_stake_success() {
-    // init context
+fn test_checkpoint_reconfigure_current_era() {
+    // pending params can not take effect from the current era
     let mut context = Context::default();
-    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
-    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
-    let secp256k1_data_dep = CellDep::new_builder()
-        .out_point(secp256k1_data_out_point)
-        .build();
-    let contract_bin: Bytes = Loader::default().load_binary("stake");
-    let contract_out_point = context.deploy_cell(contract_bin);
-    let contract_dep = CellDep::new_builder()
-        .out_point(contract_out_point.clone())
-        .build();
-    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
-    let always_success_lock_script = context
-        .build_script(&always_success_out_point, Bytes::from(vec![1]))
-        .expect("always_success script");
-    let type_id_type_script = context
-        .build_script(&always_success_out_point, Bytes::new())
-        .expect("type_id script");
-    let always_success_script_dep = CellDep::new_builder()
-        .out_point(always_success_out_point)
-        .build();
+    let scene = CheckpointReconfigureScene::new(&mut context);
+    let output_data = scene.reconfigured_data(pending_checkpoint_params(1, 2));
+    let tx = scene.reconfigure_tx(&mut context, &output_data);
 
-    // prepare checkpoint_args and checkpoint_data
-    let keypair = Generator::random_keypair();
-    let stake_args = axon::StakeLockArgs::new_builder()
-        .admin_identity(axon_identity(&keypair.1))
-        .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
-        .node_identity(axon_identity_opt(&keypair.1))
-        .build();
-    let stake_data = axon_stake_data(70, &type_id_type_script.calc_script_hash(), vec![]);
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_EFFECTIVE_ERA);
+}
 
-    // prepare checkpoint lock_script
-    let stake_lock_script = context
-        .build_script(&contract_out_point, stake_args.as_bytes())
-        .expect("stake script");
+#[test]
+fn test_checkpoint_reconfigure_zero_half_period() {
+    // the halving schedule can not take pending params without half_period
+    let mut context = Context::default();
+    let scene = CheckpointReconfigureScene::new(&mut context);
+    let output_data = scene.reconfigured_data(pending_checkpoint_params(2, 0));
+    let tx = scene.reconfigure_tx(&mut context, &output_data);
 
-    // prepare tx inputs and outputs
-    let inputs = vec![
-        // stake cell
-        CellInput::new_builder()
-            .previous_output(
-                context.create_cell(
-                    CellOutput::new_builder()
-                        .capacity(1000.pack())
-                        .lock(stake_lock_script.clone())
-                        .type_(Some(type_id_type_script.clone()).pack())
-                        .build(),
-                    stake_data.as_bytes(),
-                ),
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_PENDING_PARAMS);
+}
+
+#[test]
+fn test_checkpoint_reconfigure_period_interval() {
+    // period_interval is not one of the pending params
+    let mut context = Context::default();
+    let scene = CheckpointReconfigureScene::new(&mut context);
+    let output_data = scene
+        .reconfigured_data(pending_checkpoint_params(2, 2))
+        .as_builder()
+        .period_interval(axon_byte4(200))
+        .build();
+    let tx = scene.reconfigure_tx(&mut context, &output_data);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_CHECKPOINT_DATA_MISMATCH);
+}
+
+// stake_infos of validators staking `stakes` in era 0, and validator i has identity [i + 1; 20],
+// l2_address [i + 0x10; 20] and the BLS key of seed i + 1
+fn checkpoint_validators(stakes: &[u128]) -> Vec<axon::StakeInfo> {
+    stakes
+        .iter()
+        .enumerate()
+        .map(|(i, stake_amount)| {
+            axon_validator_info(
+                &[i as u8 + 1; 20],
+                &[i as u8 + 0x10; 20],
+                &bls_keypair(i as u64 + 1).1,
+                *stake_amount,
+                0,
             )
-            .build(),
-    ];
-    let outputs = vec![
-        // withdrawal cell
-        CellOutput::new_builder()
-            .capacity(1000.pack())
-            .lock(stake_lock_script)
-            .type_(Some(type_id_type_script.clone()).pack())
-            .build(),
-    ];
+        })
+        .collect()
+}
 
-    // prepare outputs_data
-    let outputs_data = vec![stake_data.as_bytes()];
+// delegate_info of delegator of identity [delegator; 20] delegating `amount` to `validator` from
+// era 0
+fn checkpoint_delegate_info(
+    delegator: u8,
+    validator: &axon::StakeInfo,
+    amount: u128,
+) -> axon::DelegateInfo {
+    axon::DelegateInfo::new_builder()
+        .delegator_identity(axon_stake_info(&[delegator; 20], 0).identity())
+        .validator_identity(validator.identity())
+        .amount(axon_byte16(amount))
+        .inauguration_era(axon_byte8(0))
+        .build()
+}
 
-    // prepare checkpoint cell_dep
-    let checkpoint_data = axon_checkpoint_data(1, 1, &type_id_type_script.calc_script_hash());
-    let checkpoint_script_dep = CellDep::new_builder()
-        .out_point(
-            context.create_cell(
-                CellOutput::new_builder()
-                    .capacity(1000.pack())
-                    .lock(always_success_lock_script)
-                    .type_(Some(type_id_type_script).pack())
+// L2_bitmap marking `positions` in the validator set
+fn l2_bitmap(positions: &[usize]) -> Vec<u8> {
+    vec![positions.iter().fold(0u8, |bitmap, i| bitmap | 0x80 >> i)]
+}
+
+// checkpoint cell committing L2 blocks proposed by validator 0 in checkpoint mode, whose
+// validators are in the stake cell depended on, and the reward of which goes to Withdrawal AT
+// cells locked until unlock_period of 2 after the committed period
+struct CheckpointModeScene {
+    deployment: Deployment,
+    checkpoint_output: CellOutput,
+    admin_identity: axon::Identity,
+    type_id_hash: Byte32,
+    at_type_script: Script,
+    stake_type_hash: Byte32,
+    withdrawal_lock_code_hash: Byte32,
+    validators: Vec<axon::StakeInfo>,
+}
+
+impl CheckpointModeScene {
+    fn new(context: &mut Context, validators: Vec<axon::StakeInfo>, quorum_size: u8) -> Self {
+        Self::with_delegate_infos(context, validators, quorum_size, vec![])
+    }
+
+    // scene whose stake cell also records `delegate_infos` to validators
+    fn with_delegate_infos(
+        context: &mut Context,
+        validators: Vec<axon::StakeInfo>,
+        quorum_size: u8,
+        delegate_infos: Vec<axon::DelegateInfo>,
+    ) -> Self {
+        // withdrawal lock is referenced by the type hash of its code
+        let mut deployment = Deployment::new(context, "checkpoint");
+        let withdrawal_lock_code_hash = deployment.deploy_by_type(context, "withdrawal", &[4]);
+        let type_id_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[2]);
+        let stake_type_script = deployment.always_success_script(context, &[3]);
+
+        // prepare stake cell_dep
+        let type_id_hash = type_id_type_script.calc_script_hash();
+        let stake_data = axon_stake_data(quorum_size, &type_id_hash, validators.clone())
+            .as_builder()
+            .delegate_infos(
+                axon::DelegateInfoVec::new_builder()
+                    .set(delegate_infos)
                     .build(),
-                checkpoint_data.as_bytes(),
-            ),
+            )
+            .build();
+        deployment.add_cell_dep(context, &stake_type_script, stake_data.as_bytes());
+
+        // prepare checkpoint cell of admin
+        let admin_keypair = Generator::random_keypair();
+        let checkpoint_args = axon::CheckpointLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .type_id_hash(axon_byte32(&type_id_hash))
+            .build();
+        let checkpoint_output = cell_output(
+            &deployment.script(context, checkpoint_args.as_bytes()),
+            Some(&type_id_type_script),
+        );
+        CheckpointModeScene {
+            deployment,
+            checkpoint_output,
+            admin_identity: axon_identity(&admin_keypair.1),
+            type_id_hash,
+            at_type_script,
+            stake_type_hash: stake_type_script.calc_script_hash(),
+            withdrawal_lock_code_hash,
+            validators,
+        }
+    }
+
+    // checkpoint data of `period` in era 0 on top of the L2 block of hash [1u8; 32], which
+    // rewards proposer with 1000 AT halved every 100 periods under the supply_cap of 1_000_000 AT
+    fn checkpoint_data(&self, period: u64) -> axon::CheckpointLockCellData {
+        axon::CheckpointLockCellData::new_builder()
+            .state(Byte::from(1))
+            .period(axon_byte8(period))
+            .era(axon_byte8(0))
+            .block_hash(axon_hash(&[1u8; 32]))
+            .period_interval(axon_byte4(100))
+            .era_period(axon_byte4(10))
+            .unlock_period(axon_byte4(2))
+            .base_reward(axon_byte16(1000))
+            .half_period(axon_byte8(100))
+            .sudt_type_hash(axon_byte32(&self.at_type_script.calc_script_hash()))
+            .stake_type_hash(axon_byte32(&self.stake_type_hash))
+            .withdrawal_lock_code_hash(axon_byte32(&self.withdrawal_lock_code_hash))
+            .reward_schedule(
+                axon::RewardSchedule::new_builder()
+                    .supply_cap(axon_byte16(1_000_000))
+                    .build(),
+            )
+            .build()
+    }
+
+    // checkpoint data committing `period` of `block_hash` after `checkpoint_data`, which has
+    // minted `minted_amount` of AT in total
+    fn next_checkpoint_data(
+        &self,
+        checkpoint_data: &axon::CheckpointLockCellData,
+        period: u64,
+        block_hash: &[u8; 32],
+        minted_amount: u128,
+    ) -> axon::CheckpointLockCellData {
+        checkpoint_data
+            .clone()
+            .as_builder()
+            .period(axon_byte8(period))
+            .era(axon_byte8(period / 10))
+            .block_hash(axon_hash(block_hash))
+            .minted_amount(axon_byte16(minted_amount))
+            .build()
+    }
+
+    // header of the last L2 block of `period` proposed by validator 0
+    fn header(&self, period: u64) -> Vec<u8> {
+        let mut proposer = [0u8; 20];
+        proposer.copy_from_slice(&self.validators[0].l2_address().raw_data());
+        axon_header(&proposer, period * 100, &[1u8; 32])
+    }
+
+    // aggregated signature of `message` signed by `signers`
+    fn sign(&self, signers: &[usize], message: &[u8; 32]) -> Vec<u8> {
+        let secret_keys = signers
+            .iter()
+            .map(|i| bls_keypair(*i as u64 + 1).0)
+            .collect::<Vec<_>>();
+        bls_aggregate_sign(&secret_keys, message)
+    }
+
+    // L2 checkpoint of `period` signed by `signers`, who are marked in L2_bitmap by their own
+    // indices, and the block hash it commits
+    fn l2_checkpoint(&self, period: u64, signers: &[usize]) -> (Vec<u8>, [u8; 32]) {
+        let header = self.header(period);
+        let block_hash = keccak256(&header);
+        let signature = self.sign(signers, &block_hash);
+        let checkpoint = axon_checkpoint(
+            &header,
+            period * 100,
+            &block_hash,
+            &signature,
+            &l2_bitmap(signers),
+        );
+        (checkpoint, block_hash)
+    }
+
+    // Withdrawal AT cell of `validator`
+    fn withdrawal_output(&self, validator: usize) -> CellOutput {
+        self.withdrawal_output_of(self.validators[validator].identity())
+    }
+
+    // Withdrawal AT cell of `identity`, which can be a validator or a delegator
+    fn withdrawal_output_of(&self, identity: axon::Identity) -> CellOutput {
+        let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+            .admin_identity(self.admin_identity.clone())
+            .checkpoint_cell_type_hash(axon_byte32(&self.type_id_hash))
+            .node_identity(axon::IdentityOpt::new_builder().set(Some(identity)).build())
+            .build();
+        let withdrawal_lock_script = Script::new_builder()
+            .code_hash(self.withdrawal_lock_code_hash.clone())
+            .hash_type(ScriptHashType::Type.into())
+            .args(withdrawal_args.as_bytes().pack())
+            .build();
+        cell_output(&withdrawal_lock_script, Some(&self.at_type_script))
+    }
+
+    // transaction updating the checkpoint cell from `input_data` to `output_data` with
+    // `l2_checkpoint` in witness, to which the Withdrawal AT cells are added
+    fn checkpoint_tx(
+        &self,
+        context: &mut Context,
+        input_data: &axon::CheckpointLockCellData,
+        output_data: &axon::CheckpointLockCellData,
+        l2_checkpoint: Vec<u8>,
+    ) -> TestTx {
+        let witness = WitnessArgs::new_builder()
+            .lock(Some(Bytes::from(l2_checkpoint)).pack())
+            .input_type(Some(Bytes::from(vec![1])).pack())
+            .build();
+        TestTx::default()
+            .input(context, &self.checkpoint_output, input_data.as_bytes())
+            .output(&self.checkpoint_output, output_data.as_bytes())
+            .witness(0, witness)
+    }
+}
+
+#[test]
+fn test_checkpoint_mode_success() {
+    // validators 0, 1 and 2 commit period 2, and proposer takes the reward of 1000 AT
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_last_halving() {
+    // u128::MAX AT halved 127 times leaves 1 AT
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(127)
+        .as_builder()
+        .base_reward(axon_byte16(u128::MAX))
+        .half_period(axon_byte8(1))
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .supply_cap(axon_byte16(u128::MAX))
+                .build(),
         )
         .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(128, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 128, &block_hash, 1);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1, 130),
+        )
+        .build(&scene.deployment);
 
-    // prepare signed tx
-    let tx = TransactionBuilder::default()
-        .inputs(inputs)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .cell_dep(contract_dep)
-        .cell_dep(always_success_script_dep)
-        .cell_dep(secp256k1_data_dep)
-        .cell_dep(checkpoint_script_dep)
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_halving_exhausted() {
+    // the divisor of 2^128 no longer fits in u128, so nothing is minted
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(128)
+        .as_builder()
+        .base_reward(axon_byte16(u128::MAX))
+        .half_period(axon_byte8(1))
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .supply_cap(axon_byte16(u128::MAX))
+                .build(),
+        )
         .build();
-    let tx = context.complete_tx(tx);
-    let tx = sign_tx(tx, &keypair.0, 1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(129, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 129, &block_hash, 0);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(0, 131),
+        )
+        .build(&scene.deployment);
 
     // run
     let cycles = context
-        .verify_tx(&tx, MAX_CYCLES)
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_reward_after_halving_exhausted() {
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(128)
+        .as_builder()
+        .base_reward(axon_byte16(u128::MAX))
+        .half_period(axon_byte8(1))
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .supply_cap(axon_byte16(u128::MAX))
+                .build(),
+        )
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(129, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 129, &block_hash, 1);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1, 131),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_AT_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_linear_decay() {
+    // 1000 AT decays by 100 AT every period
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(3)
+        .as_builder()
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .version(1u8.into())
+                .decay_reward(axon_byte16(100))
+                .supply_cap(axon_byte16(1_000_000))
+                .build(),
+        )
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(4, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 4, &block_hash, 700);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(700, 6),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_tail_reward() {
+    // the decayed reward never drops below tail_reward
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(20)
+        .as_builder()
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .version(1u8.into())
+                .decay_reward(axon_byte16(100))
+                .tail_reward(axon_byte16(50))
+                .supply_cap(axon_byte16(1_000_000))
+                .build(),
+        )
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(21, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 21, &block_hash, 50);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(50, 23),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_supply_cap() {
+    // only 500 AT remains under supply_cap
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(1)
+        .as_builder()
+        .minted_amount(axon_byte16(999_500))
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1_000_000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(500, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_minted_amount_mismatch() {
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 999);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_MINTED_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_unknown_reward_schedule() {
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene
+        .checkpoint_data(1)
+        .as_builder()
+        .reward_schedule(
+            axon::RewardSchedule::new_builder()
+                .version(2u8.into())
+                .supply_cap(axon_byte16(1_000_000))
+                .build(),
+        )
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_REWARD_SCHEDULE);
+}
+
+#[test]
+fn test_checkpoint_mode_weighted_validators() {
+    // validators 2, 3 and 0 of stake 400, 300 and 200 make up the validator set of quorum_size 3
+    // in order, and validator 1 of stake 100 is cut off
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(
+        &mut context,
+        checkpoint_validators(&[200, 100, 400, 300]),
+        3,
+    );
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[2, 3, 0], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_bitmap_in_stake_order() {
+    // bit 0 of L2_bitmap marks the validator of the highest stake rather than validator 0
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(
+        &mut context,
+        checkpoint_validators(&[200, 100, 400, 300]),
+        3,
+    );
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BLS_SIGNATURE_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_validator_cut_off() {
+    // position 3 in L2_bitmap is out of the validator set of quorum_size 3
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(
+        &mut context,
+        checkpoint_validators(&[200, 100, 400, 300]),
+        3,
+    );
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[2, 3, 0, 1], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2, 3]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BITMAP_OUT_OF_RANGE);
+}
+
+#[test]
+fn test_checkpoint_mode_signer_rewards() {
+    // validators 1, 2 and 0 of stake 300, 200 and 100 sign in the order of the validator set,
+    // and validator 1 takes the dust of 1 AT
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(
+        &mut context,
+        checkpoint_validators(&[100, 300, 200, 100]),
+        4,
+    );
+    let input_data = scene
+        .checkpoint_data(1)
+        .as_builder()
+        .reward_mode(1u8.into())
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(1),
+            axon_withdrawal_amount_data(501, 4),
+        )
+        .output(
+            &scene.withdrawal_output(2),
+            axon_withdrawal_amount_data(333, 4),
+        )
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(166, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_delegator_rewards() {
+    // proposer staking 100 AT takes 15% of the reward of 1000 AT as commission, and shares the
+    // rest of 850 AT with delegators of 50 and 150 AT, which is 141.67 and 425 AT, and proposer
+    // takes the dust of rounding
+    let mut context = Context::default();
+    let mut validators = checkpoint_validators(&[100; 4]);
+    validators[0] = validators[0]
+        .clone()
+        .as_builder()
+        .commission_rate(15u8.into())
+        .build();
+    let delegate_infos = vec![
+        checkpoint_delegate_info(0x21, &validators[0], 50),
+        checkpoint_delegate_info(0x22, &validators[0], 150),
+    ];
+    let scene = CheckpointModeScene::with_delegate_infos(
+        &mut context,
+        validators,
+        4,
+        delegate_infos.clone(),
+    );
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(434, 4),
+        )
+        .output(
+            &scene.withdrawal_output_of(delegate_infos[0].delegator_identity()),
+            axon_withdrawal_amount_data(141, 4),
+        )
+        .output(
+            &scene.withdrawal_output_of(delegate_infos[1].delegator_identity()),
+            axon_withdrawal_amount_data(425, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_signer_delegator_rewards() {
+    // validators 0, 1 and 2 of weight 250, 100 and 100 sign, so validator 0 takes 555 AT and the
+    // dust of 1 AT, of which 15% is its commission of 83 AT, and delegators of 50 and 100 AT
+    // share 94 and 189 AT of the rest of 473 AT, and validator 0 takes the dust of 1 AT again
+    let mut context = Context::default();
+    let mut validators = checkpoint_validators(&[100; 4]);
+    validators[0] = validators[0]
+        .clone()
+        .as_builder()
+        .commission_rate(15u8.into())
+        .build();
+    let delegate_infos = vec![
+        checkpoint_delegate_info(0x21, &validators[0], 50),
+        checkpoint_delegate_info(0x22, &validators[0], 100),
+    ];
+    let scene = CheckpointModeScene::with_delegate_infos(
+        &mut context,
+        validators,
+        4,
+        delegate_infos.clone(),
+    );
+    let input_data = scene
+        .checkpoint_data(1)
+        .as_builder()
+        .reward_mode(1u8.into())
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(273, 4),
+        )
+        .output(
+            &scene.withdrawal_output_of(delegate_infos[0].delegator_identity()),
+            axon_withdrawal_amount_data(94, 4),
+        )
+        .output(
+            &scene.withdrawal_output_of(delegate_infos[1].delegator_identity()),
+            axon_withdrawal_amount_data(189, 4),
+        )
+        .output(
+            &scene.withdrawal_output(1),
+            axon_withdrawal_amount_data(222, 4),
+        )
+        .output(
+            &scene.withdrawal_output(2),
+            axon_withdrawal_amount_data(222, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
         .expect("pass verification");
     println!("consume cycles: {}", cycles);
 }
+
+#[test]
+fn test_checkpoint_mode_delegator_reward_to_validator() {
+    // the reward of delegator of 50 AT goes to proposer instead
+    let mut context = Context::default();
+    let mut validators = checkpoint_validators(&[100; 4]);
+    validators[0] = validators[0]
+        .clone()
+        .as_builder()
+        .commission_rate(15u8.into())
+        .build();
+    let delegate_infos = vec![
+        checkpoint_delegate_info(0x21, &validators[0], 50),
+        checkpoint_delegate_info(0x22, &validators[0], 150),
+    ];
+    let scene = CheckpointModeScene::with_delegate_infos(
+        &mut context,
+        validators,
+        4,
+        delegate_infos.clone(),
+    );
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(575, 4),
+        )
+        .output(
+            &scene.withdrawal_output_of(delegate_infos[1].delegator_identity()),
+            axon_withdrawal_amount_data(425, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_signer_reward_missing() {
+    // the reward of validator 0 goes to validator 1 instead
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(
+        &mut context,
+        checkpoint_validators(&[100, 300, 200, 100]),
+        4,
+    );
+    let input_data = scene
+        .checkpoint_data(1)
+        .as_builder()
+        .reward_mode(1u8.into())
+        .build();
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(1),
+            axon_withdrawal_amount_data(667, 4),
+        )
+        .output(
+            &scene.withdrawal_output(2),
+            axon_withdrawal_amount_data(333, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_bad_rlp() {
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (mut checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    checkpoint.pop();
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_CHECKPOINT_RLP_TRUNCATED);
+}
+
+#[test]
+fn test_checkpoint_mode_rlp_trailing_bytes() {
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (mut checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    checkpoint.push(0);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_CHECKPOINT_RLP_TRAILING_BYTES);
+}
+
+#[test]
+fn test_checkpoint_mode_block_hash_mismatch() {
+    // the checkpoint claims a block_hash other than the keccak hash of its header
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = [2u8; 32];
+    let signature = scene.sign(&[0, 1, 2], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BLOCK_HASH_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_proof_number_mismatch() {
+    // the proof is signed for block 201 while the header is of block 200
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[0, 1, 2], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        201,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_CHECKPOINT_BLOCK_NUMBER);
+}
+
+#[test]
+fn test_checkpoint_mode_wrong_bitmap() {
+    // L2_bitmap marks validator 3 instead of validator 2 who signs
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[0, 1, 2], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 3]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BLS_SIGNATURE_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_wrong_message() {
+    // validators sign a message other than block_hash
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[0, 1, 2], &[2u8; 32]);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BLS_SIGNATURE_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_identity_pub_key() {
+    // the point at infinity is a valid G1 encoding, but no valid public key, so validator 2 who
+    // registers it adds nothing to the aggregated signature of validators 0, 1 and 3
+    let mut identity_pub_key = vec![0u8; 97];
+    identity_pub_key[0] = 0x04;
+    identity_pub_key[1] = 0x40;
+    let mut validators = checkpoint_validators(&[100; 4]);
+    validators[2] = validators[2]
+        .clone()
+        .as_builder()
+        .bls_pub_key(axon::Byte97::new_unchecked(identity_pub_key.into()))
+        .build();
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, validators, 4);
+    let input_data = scene.checkpoint_data(1);
+    let header = scene.header(2);
+    let block_hash = keccak256(&header);
+    let signature = scene.sign(&[0, 1, 3], &block_hash);
+    let checkpoint = axon_checkpoint(
+        &header,
+        200,
+        &block_hash,
+        &signature,
+        &l2_bitmap(&[0, 1, 2, 3]),
+    );
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BLS_SIGNATURE_MISMATCH);
+}
+
+#[test]
+fn test_checkpoint_mode_insufficient_signers() {
+    // 2 of 4 validators are no more than 2/3 of the validator set
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_QUORUM_NOT_REACHED);
+}
+
+#[test]
+fn test_checkpoint_mode_skip_period() {
+    // period 1 can only be followed by period 2
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(3, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 3, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 5),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_CHECKPOINT_PERIOD);
+}
+
+#[test]
+fn test_checkpoint_mode_bad_era() {
+    // period 2 belongs to era 0 while era_period is 10
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene
+        .next_checkpoint_data(&input_data, 2, &block_hash, 1000)
+        .as_builder()
+        .era(axon_byte8(1))
+        .build();
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 4),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_CHECKPOINT_ERA);
+}
+
+#[test]
+fn test_checkpoint_mode_bad_withdrawal_period() {
+    // the reward of period 2 is locked until period 4, not 5
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .output(
+            &scene.withdrawal_output(0),
+            axon_withdrawal_amount_data(1000, 5),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_WITHDRAWAL_PERIOD);
+}
+
+#[test]
+fn test_checkpoint_mode_append_withdrawal() {
+    // the reward is appended to the Withdrawal AT cell of period 4 which proposer already has
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let withdrawal_output = scene.withdrawal_output(0);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .input(
+            &mut context,
+            &withdrawal_output,
+            axon_withdrawal_amount_data(500, 4),
+        )
+        .output(&withdrawal_output, axon_withdrawal_amount_data(1500, 4))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_mode_keep_withdrawal() {
+    // the Withdrawal AT cell of period 3 which proposer already has is kept as it is
+    let mut context = Context::default();
+    let scene = CheckpointModeScene::new(&mut context, checkpoint_validators(&[100; 4]), 4);
+    let input_data = scene.checkpoint_data(1);
+    let (checkpoint, block_hash) = scene.l2_checkpoint(2, &[0, 1, 2]);
+    let output_data = scene.next_checkpoint_data(&input_data, 2, &block_hash, 1000);
+    let withdrawal_output = scene.withdrawal_output(0);
+    let tx = scene
+        .checkpoint_tx(&mut context, &input_data, &output_data, checkpoint)
+        .input(
+            &mut context,
+            &withdrawal_output,
+            axon_withdrawal_amount_data(500, 3),
+        )
+        .output(&withdrawal_output, axon_withdrawal_amount_data(1000, 4))
+        .output(&withdrawal_output, axon_withdrawal_amount_data(500, 3))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_success() {
+    // init context
+    let mut context = Context::default();
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+    let contract_bin: Bytes = Loader::default().load_binary("withdrawal");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let type_id_type_script = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("type_id script");
+    let at_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("at script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare checkpoint_args and checkpoint_data
+    let keypair = Generator::random_keypair();
+    let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+        .admin_identity(axon_identity(&keypair.1))
+        .checkpoint_cell_type_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+        .node_identity(axon_identity_opt(&keypair.1))
+        .build();
+    let withdrawal_data = axon_withdrawal_data(1);
+
+    // prepare checkpoint lock_script
+    let withdrawal_lock_script = context
+        .build_script(&contract_out_point, withdrawal_args.as_bytes())
+        .expect("withdrawal script");
+
+    // prepare tx inputs and outputs
+    let inputs = vec![
+        // withdrawal cell
+        CellInput::new_builder()
+            .previous_output(
+                context.create_cell(
+                    CellOutput::new_builder()
+                        .capacity(1000.pack())
+                        .lock(withdrawal_lock_script.clone())
+                        .type_(Some(at_type_script.clone()).pack())
+                        .build(),
+                    Bytes::from(withdrawal_data.clone()),
+                ),
+            )
+            .build(),
+    ];
+    let outputs = vec![
+        // withdrawal cell
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(withdrawal_lock_script)
+            .type_(Some(at_type_script).pack())
+            .build(),
+    ];
+
+    // prepare outputs_data
+    let outputs_data = vec![Bytes::from(withdrawal_data)];
+
+    // prepare checkpoint cell_dep
+    let checkpoint_data = axon_checkpoint_data(1, 1, &type_id_type_script.calc_script_hash());
+    let checkpoint_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script)
+                    .type_(Some(type_id_type_script).pack())
+                    .build(),
+                checkpoint_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    // prepare signed tx
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
+        .cell_dep(checkpoint_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+    let tx = sign_tx(tx, &keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+// Withdrawal AT cells under the withdrawal lock of node, which expire 5 periods after their
+// period and are swept to the treasury lock by admin then
+struct WithdrawalScene {
+    deployment: Deployment,
+    admin_keypair: (Privkey, Pubkey),
+    node_keypair: (Privkey, Pubkey),
+    checkpoint_type_script: Script,
+    at_type_script: Script,
+    expiry: axon::WithdrawalExpiry,
+    withdrawal_output: CellOutput,
+    // AT cells of the always_success lock, which AT is unlocked to or deposited from
+    free_output: CellOutput,
+    treasury_output: CellOutput,
+}
+
+impl WithdrawalScene {
+    fn new(context: &mut Context) -> Self {
+        let deployment = Deployment::new(context, "withdrawal");
+        let always_success_lock_script = deployment.always_success_script(context, &[1]);
+        let treasury_lock_script = deployment.always_success_script(context, &[9]);
+        let checkpoint_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[2]);
+        let expiry = axon::WithdrawalExpiry::new_builder()
+            .expiry_periods(axon_byte8(5))
+            .treasury_lock_hash(axon_byte32(&treasury_lock_script.calc_script_hash()))
+            .build();
+        let mut scene = WithdrawalScene {
+            deployment,
+            admin_keypair: Generator::random_keypair(),
+            node_keypair: Generator::random_keypair(),
+            free_output: cell_output(&always_success_lock_script, Some(&at_type_script)),
+            treasury_output: cell_output(&treasury_lock_script, Some(&at_type_script)),
+            withdrawal_output: CellOutput::default(),
+            checkpoint_type_script,
+            at_type_script,
+            expiry,
+        };
+        let withdrawal_output = scene.withdrawal_output_of(context, &scene.node_keypair.1);
+        scene.withdrawal_output = withdrawal_output;
+        scene
+    }
+
+    // Withdrawal AT cell under the withdrawal lock of `node_pubkey`
+    fn withdrawal_output_of(&self, context: &mut Context, node_pubkey: &Pubkey) -> CellOutput {
+        let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+            .admin_identity(axon_identity(&self.admin_keypair.1))
+            .checkpoint_cell_type_hash(axon_byte32(&self.checkpoint_type_script.calc_script_hash()))
+            .node_identity(axon_identity_opt(node_pubkey))
+            .expiry(
+                axon::WithdrawalExpiryOpt::new_builder()
+                    .set(Some(self.expiry.clone()))
+                    .build(),
+            )
+            .build();
+        cell_output(
+            &self.deployment.script(context, withdrawal_args.as_bytes()),
+            Some(&self.at_type_script),
+        )
+    }
+
+    // depend on the checkpoint cell in `period`
+    fn add_checkpoint(&mut self, context: &mut Context, period: u64) {
+        let checkpoint_data = axon_checkpoint_data(period, 1, &Byte32::default());
+        self.deployment.add_cell_dep(
+            context,
+            &self.checkpoint_type_script,
+            checkpoint_data.as_bytes(),
+        );
+    }
+}
+
+#[test]
+fn test_withdrawal_batch_unlock_success() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 0),
+        )
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.free_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_batch_unlock_immature() {
+    // the Withdrawal AT cell of period 2 has not matured in period 1
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 2),
+        )
+        .output(&scene.free_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BAD_PERIOD);
+}
+
+#[test]
+fn test_withdrawal_partial_unlock_success() {
+    // 40 of 100 AT goes back to the Withdrawal AT cell of its original period
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.withdrawal_output, axon_withdrawal_amount_data(40, 1))
+        .output(&scene.free_output, sudt_data(60))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_partial_unlock_change_period() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.withdrawal_output, axon_withdrawal_amount_data(40, 0))
+        .output(&scene.free_output, sudt_data(60))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BAD_PERIOD);
+}
+
+#[test]
+fn test_withdrawal_partial_unlock_change_amount() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(150, 1),
+        )
+        .output(&scene.free_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_TOTAL_SUDT_MISMATCH);
+}
+
+#[test]
+fn test_withdrawal_unlock_truncated_checkpoint() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    let mut checkpoint_data = axon_checkpoint_data(1, 1, &Byte32::default())
+        .as_bytes()
+        .to_vec();
+    checkpoint_data.pop();
+    scene.deployment.add_cell_dep(
+        &mut context,
+        &scene.checkpoint_type_script,
+        checkpoint_data.into(),
+    );
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.free_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_ENCODING);
+}
+
+#[test]
+fn test_withdrawal_unlock_duplicate_checkpoint() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.free_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.node_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_CHECKPOINT_CELLDEP);
+}
+
+#[test]
+fn test_withdrawal_acp_deposit_success() {
+    // AT is deposited into the existing bucket and into a new bucket of a later period
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .input(&mut context, &scene.free_output, sudt_data(100))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(150, 10),
+        )
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(50, 20),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_acp_untyped_cell() {
+    // AT is deposited into a cell under withdrawal lock without type script
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let untyped_output = cell_output(&scene.withdrawal_output.lock(), None);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .input(&mut context, &scene.free_output, sudt_data(50))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .output(&untyped_output, axon_withdrawal_amount_data(50, 20))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SOME_WITHDRAWAL_TYPE_EMPTY);
+}
+
+#[test]
+fn test_withdrawal_acp_foreign_type_cell() {
+    // a cell of another type under withdrawal lock is spent together with the Withdrawal AT cell
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let foreign_type_script = scene.deployment.always_success_script(&mut context, &[3]);
+    let foreign_output = cell_output(&scene.withdrawal_output.lock(), Some(&foreign_type_script));
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .input(&mut context, &foreign_output, sudt_data(50))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_WITHDRAWAL_TYPE_HASH);
+}
+
+#[test]
+fn test_withdrawal_acp_rewrite_period() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 11),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BAD_PERIOD);
+}
+
+#[test]
+fn test_withdrawal_acp_move_to_earlier_bucket() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 20),
+        )
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(200, 10),
+        )
+        .output(&scene.withdrawal_output, axon_withdrawal_amount_data(0, 20))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BUCKET_MISMATCH);
+}
+
+#[test]
+fn test_withdrawal_acp_move_to_later_bucket() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 20),
+        )
+        .output(&scene.withdrawal_output, axon_withdrawal_amount_data(0, 10))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(200, 20),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BUCKET_MISMATCH);
+}
+
+#[test]
+fn test_withdrawal_acp_move_to_new_bucket() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 10),
+        )
+        .output(&scene.withdrawal_output, axon_withdrawal_amount_data(0, 10))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, u64::MAX),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_BUCKET_MISMATCH);
+}
+
+#[test]
+fn test_withdrawal_sweep_success() {
+    // the Withdrawal AT cell of period 1 expires in period 6
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 6);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.treasury_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.admin_keypair.0, 5);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_sweep_two_locks() {
+    // Withdrawal AT cells of two nodes are swept to the same treasury lock together
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 6);
+    let other_withdrawal_output =
+        scene.withdrawal_output_of(&mut context, &Generator::random_keypair().1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .input(
+            &mut context,
+            &other_withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.treasury_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx_at(tx, &scene.admin_keypair.0, 0, vec![5]);
+    let tx = sign_tx_at(tx, &scene.admin_keypair.0, 1, vec![5]);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdrawal_sweep_two_locks_to_one_output() {
+    // the treasury lock receives AT of only one of the two swept locks, which each lock group
+    // would take as its own on its own
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 6);
+    let other_withdrawal_output =
+        scene.withdrawal_output_of(&mut context, &Generator::random_keypair().1);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .input(
+            &mut context,
+            &other_withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.treasury_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx_at(tx, &scene.admin_keypair.0, 0, vec![5]);
+    let tx = sign_tx_at(tx, &scene.admin_keypair.0, 1, vec![5]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_TOTAL_SUDT_MISMATCH);
+}
+
+#[test]
+fn test_withdrawal_sweep_not_expired() {
+    let mut context = Context::default();
+    let mut scene = WithdrawalScene::new(&mut context);
+    scene.add_checkpoint(&mut context, 5);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 1),
+        )
+        .output(&scene.treasury_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.admin_keypair.0, 5);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_WITHDRAWAL_NOT_EXPIRED);
+}
+
+#[test]
+fn test_stake_success() {
+    // init context
+    let mut context = Context::default();
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+    let contract_bin: Bytes = Loader::default().load_binary("stake");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let type_id_type_script = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("type_id script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point)
+        .build();
+
+    // prepare checkpoint_args and checkpoint_data
+    let keypair = Generator::random_keypair();
+    let stake_args = axon::StakeLockArgs::new_builder()
+        .admin_identity(axon_identity(&keypair.1))
+        .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+        .node_identity(axon_identity_opt(&keypair.1))
+        .build();
+    let stake_data = axon_stake_data(70, &type_id_type_script.calc_script_hash(), vec![]);
+
+    // prepare checkpoint lock_script
+    let stake_lock_script = context
+        .build_script(&contract_out_point, stake_args.as_bytes())
+        .expect("stake script");
+
+    // prepare tx inputs and outputs
+    let inputs = vec![
+        // stake cell
+        CellInput::new_builder()
+            .previous_output(
+                context.create_cell(
+                    CellOutput::new_builder()
+                        .capacity(1000.pack())
+                        .lock(stake_lock_script.clone())
+                        .type_(Some(type_id_type_script.clone()).pack())
+                        .build(),
+                    stake_data.as_bytes(),
+                ),
+            )
+            .build(),
+    ];
+    let outputs = vec![
+        // withdrawal cell
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(stake_lock_script)
+            .type_(Some(type_id_type_script.clone()).pack())
+            .build(),
+    ];
+
+    // prepare outputs_data
+    let outputs_data = vec![stake_data.as_bytes()];
+
+    // prepare checkpoint cell_dep
+    let checkpoint_data = axon_checkpoint_data(1, 1, &type_id_type_script.calc_script_hash());
+    let checkpoint_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script)
+                    .type_(Some(type_id_type_script).pack())
+                    .build(),
+                checkpoint_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    // prepare signed tx
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
+        .cell_dep(checkpoint_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+    let tx = sign_tx(tx, &keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+// stake cell of admin and companion cell of node, where node appends its stake_info with the BLS
+// public key of secret key 1 in update mode, or unstakes with unbonding_eras of 3 in unstake
+// mode, or admin rewrites stake_data in admin mode, while the checkpoint cell depended on is in era 0 with unlock_period of 2
+struct StakeUpdateScene {
+    deployment: Deployment,
+    admin_keypair: (Privkey, Pubkey),
+    node_keypair: (Privkey, Pubkey),
+    // BLS public key of secret key 1 and its proof of possession
+    bls_pub_key: Vec<u8>,
+    proof: Vec<u8>,
+    checkpoint_type_hash: Byte32,
+    at_type_hash: Byte32,
+    companion_output: CellOutput,
+    stake_output: CellOutput,
+    // AT cell under stake lock, which holds the stake of node
+    at_output: CellOutput,
+    // Withdrawal AT cell of node, which receives the stake released in unstake mode
+    withdrawal_output: CellOutput,
+}
+
+impl StakeUpdateScene {
+    fn new(context: &mut Context) -> Self {
+        let mut deployment = Deployment::new(context, "stake");
+        let withdrawal_lock_code_hash = deployment.deploy_by_type(context, "withdrawal", &[4]);
+        let type_id_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[2]);
+        let checkpoint_type_script = deployment.always_success_script(context, &[3]);
+
+        // prepare stake_args of both stake cell and companion cell
+        let admin_keypair = Generator::random_keypair();
+        let node_keypair = Generator::random_keypair();
+        let stake_args = axon::StakeLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+            .build();
+        let companion_args = stake_args
+            .clone()
+            .as_builder()
+            .node_identity(axon_identity_opt(&node_keypair.1))
+            .build();
+        let stake_lock_script = deployment.script(context, stake_args.as_bytes());
+        let companion_lock_script = deployment.script(context, companion_args.as_bytes());
+
+        // prepare Withdrawal AT cell of node
+        let checkpoint_type_hash = checkpoint_type_script.calc_script_hash();
+        let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .checkpoint_cell_type_hash(axon_byte32(&checkpoint_type_hash))
+            .node_identity(axon_identity_opt(&node_keypair.1))
+            .build();
+        let withdrawal_lock_script = Script::new_builder()
+            .code_hash(withdrawal_lock_code_hash.clone())
+            .hash_type(ScriptHashType::Type.into())
+            .args(withdrawal_args.as_bytes().pack())
+            .build();
+
+        // prepare checkpoint cell_dep
+        let checkpoint_data = axon_checkpoint_data(0, 1, &Byte32::default())
+            .as_builder()
+            .era_period(axon_byte4(10))
+            .unlock_period(axon_byte4(2))
+            .withdrawal_lock_code_hash(axon_byte32(&withdrawal_lock_code_hash))
+            .build();
+        deployment.add_cell_dep(context, &checkpoint_type_script, checkpoint_data.as_bytes());
+
+        let (bls_secret_key, bls_pub_key) = bls_keypair(1);
+        StakeUpdateScene {
+            deployment,
+            admin_keypair,
+            node_keypair,
+            proof: bls_proof_of_possession(&bls_secret_key, &bls_pub_key),
+            bls_pub_key,
+            checkpoint_type_hash,
+            at_type_hash: at_type_script.calc_script_hash(),
+            companion_output: cell_output(&companion_lock_script, Some(&at_type_script)),
+            stake_output: cell_output(&stake_lock_script, Some(&type_id_type_script)),
+            at_output: cell_output(&stake_lock_script, Some(&at_type_script)),
+            withdrawal_output: cell_output(&withdrawal_lock_script, Some(&at_type_script)),
+        }
+    }
+
+    // stake_info of node staking `stake_amount` from `era` with `bls_pub_key`
+    fn stake_info(&self, bls_pub_key: &[u8], era: u64, stake_amount: u128) -> axon::StakeInfo {
+        axon_stake_info(&blake160(&self.node_keypair.1.serialize()), era)
+            .as_builder()
+            .bls_pub_key(axon::Byte97::new_unchecked(bls_pub_key.to_vec().into()))
+            .stake_amount(axon_byte16(stake_amount))
+            .build()
+    }
+
+    fn stake_data(&self, stake_infos: Vec<axon::StakeInfo>) -> axon::StakeLockCellData {
+        axon_stake_data(70, &self.checkpoint_type_hash, stake_infos)
+            .as_builder()
+            .sudt_type_hash(axon_byte32(&self.at_type_hash))
+            .unbonding_eras(axon_byte8(3))
+            .build()
+    }
+
+    // transaction updating stake_infos of the stake cell from `input_stake_infos` to
+    // `output_stake_infos`, to which the AT cells under stake lock are added
+    fn stake_tx(
+        &self,
+        context: &mut Context,
+        input_stake_infos: Vec<axon::StakeInfo>,
+        output_stake_infos: Vec<axon::StakeInfo>,
+    ) -> TestTx {
+        TestTx::default()
+            .input(context, &self.companion_output, Bytes::new())
+            .input(
+                context,
+                &self.stake_output,
+                self.stake_data(input_stake_infos).as_bytes(),
+            )
+            .output(&self.companion_output, Bytes::new())
+            .output(
+                &self.stake_output,
+                self.stake_data(output_stake_infos).as_bytes(),
+            )
+    }
+
+    // sign the companion witness of update mode carrying `proof` of possession
+    fn sign(&self, tx: TransactionView, proof: &[u8]) -> TransactionView {
+        let mut input_type = vec![1];
+        input_type.extend(proof);
+        sign_tx_with_input_type(tx, &self.node_keypair.0, input_type)
+    }
+}
+
+#[test]
+fn test_stake_update_success() {
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let stake_info = scene.stake_info(&scene.bls_pub_key, 2, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![], vec![stake_info])
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &scene.proof);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_update_registered_key() {
+    // bls_pub_key registered by node before needs no proof of possession
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let scheduled = scene.stake_info(bls_pub_key, 1, 0);
+    let appended = scene.stake_info(bls_pub_key, 2, 0);
+    let tx = scene
+        .stake_tx(
+            &mut context,
+            vec![scheduled.clone()],
+            vec![scheduled, appended],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_update_increase_stake() {
+    // stake_amount increases from 100 in era 1 to 150 in era 2 with 50 AT deposited
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let scheduled = scene.stake_info(bls_pub_key, 1, 100);
+    let appended = scene.stake_info(bls_pub_key, 2, 150);
+    let tx = scene
+        .stake_tx(
+            &mut context,
+            vec![scheduled.clone()],
+            vec![scheduled, appended],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(150))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_update_before_unstake() {
+    // node has unstaked all 100 AT from era 5, and can not restake 100 AT from era 2 for free
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let scheduled = vec![
+        scene.stake_info(bls_pub_key, 0, 100),
+        scene.stake_info(bls_pub_key, 5, 0),
+    ];
+    let mut stake_infos = scheduled.clone();
+    stake_infos.push(scene.stake_info(bls_pub_key, 2, 100));
+    let tx = scene
+        .stake_tx(&mut context, scheduled, stake_infos)
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_INAUGURATION_ERA);
+}
+
+#[test]
+fn test_stake_update_deposit_mismatch() {
+    // 50 AT deposited for a stake increased by 100
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let scheduled = scene.stake_info(bls_pub_key, 1, 100);
+    let appended = scene.stake_info(bls_pub_key, 2, 200);
+    let tx = scene
+        .stake_tx(
+            &mut context,
+            vec![scheduled.clone()],
+            vec![scheduled, appended],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(150))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_stake_update_without_proof() {
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let stake_info = scene.stake_info(&scene.bls_pub_key, 2, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![], vec![stake_info])
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_PROOF_OF_POSSESSION);
+}
+
+#[test]
+fn test_stake_update_rogue_key() {
+    // the rogue key cancels out validator 1 in aggregated signatures, but its owner knows no
+    // secret key of it, so the best it can show is a proof signed by secret key 2
+    let (_, victim_pub_key) = bls_keypair(1);
+    let (rogue_secret_key, _) = bls_keypair(2);
+    let rogue_pub_key = bls_rogue_pub_key(&rogue_secret_key, &victim_pub_key);
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let stake_info = scene.stake_info(&rogue_pub_key, 2, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![], vec![stake_info])
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = scene.sign(
+        tx,
+        &bls_proof_of_possession(&rogue_secret_key, &rogue_pub_key),
+    );
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_PROOF_OF_POSSESSION);
+}
+
+#[test]
+fn test_stake_unstake_success() {
+    // node unstakes all 100 AT from era 3, which is released to its Withdrawal AT cell locked
+    // until period 32, the first period of era 3 plus unlock_period
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let staked = scene.stake_info(bls_pub_key, 0, 100);
+    let unstaked = scene.stake_info(bls_pub_key, 3, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![staked.clone()], vec![staked, unstaked])
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 32),
+        )
+        .witness(1, input_type_witness(&[2]))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_unstake_within_unbonding_eras() {
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let staked = scene.stake_info(bls_pub_key, 0, 100);
+    let unstaked = scene.stake_info(bls_pub_key, 2, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![staked.clone()], vec![staked, unstaked])
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 22),
+        )
+        .witness(1, input_type_witness(&[2]))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_INAUGURATION_ERA);
+}
+
+#[test]
+fn test_stake_unstake_withdrawal_period() {
+    // the released stake can not be unlocked before the unstake takes effect
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let staked = scene.stake_info(bls_pub_key, 0, 100);
+    let unstaked = scene.stake_info(bls_pub_key, 3, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![staked.clone()], vec![staked, unstaked])
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 2),
+        )
+        .witness(1, input_type_witness(&[2]))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_WITHDRAWAL_PERIOD);
+}
+
+#[test]
+fn test_stake_unstake_withdrawal_amount() {
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let bls_pub_key = &scene.bls_pub_key;
+    let staked = scene.stake_info(bls_pub_key, 0, 100);
+    let unstaked = scene.stake_info(bls_pub_key, 3, 0);
+    let tx = scene
+        .stake_tx(&mut context, vec![staked.clone()], vec![staked, unstaked])
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(90, 32),
+        )
+        .witness(1, input_type_witness(&[2]))
+        .build(&scene.deployment);
+    let tx = scene.sign(tx, &[]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_WITHDRAWAL_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_stake_admin_success() {
+    // admin registers node staking 100 AT, which is deposited under stake lock
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let stake_info = scene.stake_info(&scene.bls_pub_key, 0, 100);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.stake_output,
+            scene.stake_data(vec![]).as_bytes(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(
+            &scene.stake_output,
+            scene.stake_data(vec![stake_info]).as_bytes(),
+        )
+        .output(&scene.at_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.admin_keypair.0, 0);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_admin_unbacked_stake() {
+    // admin raises the stake of node to 150 AT without depositing AT
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let staked = scene.stake_info(&scene.bls_pub_key, 0, 100);
+    let raised = scene.stake_info(&scene.bls_pub_key, 0, 150);
+    let tx = TestTx::default()
+        .input(
+            &mut context,
+            &scene.stake_output,
+            scene.stake_data(vec![staked]).as_bytes(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(
+            &scene.stake_output,
+            scene.stake_data(vec![raised]).as_bytes(),
+        )
+        .output(&scene.at_output, sudt_data(100))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.admin_keypair.0, 0);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_AMOUNT_MISMATCH);
+}
+
+#[test]
+fn test_stake_admin_slash_rate_overflow() {
+    // slash_rate is a percentage, so no more than the whole stake can be slashed
+    let mut context = Context::default();
+    let scene = StakeUpdateScene::new(&mut context);
+    let stake_data = scene.stake_data(vec![]);
+    let tx = TestTx::default()
+        .input(&mut context, &scene.stake_output, stake_data.as_bytes())
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(
+            &scene.stake_output,
+            stake_data
+                .as_builder()
+                .slash_rate(101u8.into())
+                .build()
+                .as_bytes(),
+        )
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.admin_keypair.0, 0);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_ADMIN_MODE);
+}
+
+// stake cell in which node staking with the BLS public key of secret key 1 is slashed by 30% in
+// slash mode, while the checkpoint cell depended on is in period 10 of era 1 with unlock_period
+// of 2, so the slash takes effect from era 3 and the released stake is locked until period 32
+struct StakeSlashScene {
+    deployment: Deployment,
+    node_pubkey_hash: [u8; 20],
+    bls_pub_key: Vec<u8>,
+    // proof of node voting for two different blocks of height 1000
+    proof: Vec<u8>,
+    checkpoint_type_hash: Byte32,
+    at_type_hash: Byte32,
+    stake_output: CellOutput,
+    // AT cell under stake lock, which holds the stake of node
+    at_output: CellOutput,
+    // Withdrawal AT cell of node, which is unlocked in slash mode as well
+    withdrawal_output: CellOutput,
+    // AT cell of the always_success lock
+    free_output: CellOutput,
+    quorum_size: u8,
+}
+
+impl StakeSlashScene {
+    fn new(context: &mut Context) -> Self {
+        // withdrawal lock is referenced by the type hash of its code, as checkpoint lock builds it
+        let mut deployment = Deployment::new(context, "stake");
+        let withdrawal_lock_code_hash = deployment.deploy_by_type(context, "withdrawal", &[4]);
+        let always_success_lock_script = deployment.always_success_script(context, &[1]);
+        let type_id_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[2]);
+        let checkpoint_type_script = deployment.always_success_script(context, &[3]);
+
+        // prepare stake_args and withdrawal_args of node
+        let admin_keypair = Generator::random_keypair();
+        let node_keypair = Generator::random_keypair();
+        let stake_args = axon::StakeLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+            .build();
+        let checkpoint_type_hash = checkpoint_type_script.calc_script_hash();
+        let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .checkpoint_cell_type_hash(axon_byte32(&checkpoint_type_hash))
+            .node_identity(axon_identity_opt(&node_keypair.1))
+            .build();
+        let stake_lock_script = deployment.script(context, stake_args.as_bytes());
+        let withdrawal_lock_script = Script::new_builder()
+            .code_hash(withdrawal_lock_code_hash.clone())
+            .hash_type(ScriptHashType::Type.into())
+            .args(withdrawal_args.as_bytes().pack())
+            .build();
+
+        // prepare checkpoint cell_dep
+        let checkpoint_data = axon_checkpoint_data(10, 1, &Byte32::default())
+            .as_builder()
+            .era(axon_byte8(1))
+            .period_interval(axon_byte4(100))
+            .era_period(axon_byte4(10))
+            .unlock_period(axon_byte4(2))
+            .stake_type_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+            .withdrawal_lock_code_hash(axon_byte32(&withdrawal_lock_code_hash))
+            .build();
+        deployment.add_cell_dep(context, &checkpoint_type_script, checkpoint_data.as_bytes());
+
+        let votes = [[1u8; 20], [2u8; 20]]
+            .iter()
+            .map(|proposer| slash_vote(1, proposer))
+            .collect::<Vec<_>>();
+        StakeSlashScene {
+            deployment,
+            node_pubkey_hash: blake160(&node_keypair.1.serialize()),
+            bls_pub_key: bls_keypair(1).1,
+            proof: rlp_list(&votes),
+            checkpoint_type_hash,
+            at_type_hash: at_type_script.calc_script_hash(),
+            stake_output: cell_output(&stake_lock_script, Some(&type_id_type_script)),
+            at_output: cell_output(&stake_lock_script, Some(&at_type_script)),
+            withdrawal_output: cell_output(&withdrawal_lock_script, Some(&at_type_script)),
+            free_output: cell_output(&always_success_lock_script, Some(&at_type_script)),
+            quorum_size: 70,
+        }
+    }
+
+    // stake_info of node staking `stake_amount` from `era`
+    fn stake_info(&self, era: u64, stake_amount: u128) -> axon::StakeInfo {
+        axon_validator_info(
+            &self.node_pubkey_hash,
+            &[5u8; 20],
+            &self.bls_pub_key,
+            stake_amount,
+            era,
+        )
+    }
+
+    // stake_info with a zeroed bls_pub_key, which marks node as slashed from `era`
+    fn slashed_stake_info(&self, era: u64) -> axon::StakeInfo {
+        axon_validator_info(&self.node_pubkey_hash, &[5u8; 20], &[0u8; 97], 0, era)
+    }
+
+    fn stake_data(&self, stake_infos: Vec<axon::StakeInfo>) -> axon::StakeLockCellData {
+        axon_stake_data(self.quorum_size, &self.checkpoint_type_hash, stake_infos)
+            .as_builder()
+            .sudt_type_hash(axon_byte32(&self.at_type_hash))
+            .unbonding_eras(axon_byte8(2))
+            .slash_rate(30u8.into())
+            .build()
+    }
+
+    // transaction updating stake_infos of the stake cell from `input_stake_infos` to
+    // `output_stake_infos` with `proof` in witness, to which the AT cells are added
+    fn slash_tx(
+        &self,
+        context: &mut Context,
+        input_stake_infos: Vec<axon::StakeInfo>,
+        output_stake_infos: Vec<axon::StakeInfo>,
+        proof: Vec<u8>,
+    ) -> TestTx {
+        let witness = input_type_witness(&[3])
+            .as_builder()
+            .lock(Some(Bytes::from(proof)).pack())
+            .build();
+        TestTx::default()
+            .input(
+                context,
+                &self.stake_output,
+                self.stake_data(input_stake_infos).as_bytes(),
+            )
+            .output(
+                &self.stake_output,
+                self.stake_data(output_stake_infos).as_bytes(),
+            )
+            .witness(0, witness)
+    }
+}
+
+// vote for the block of height 1000 proposed by `proposer`, signed by BLS secret key `seed`
+fn slash_vote(seed: u64, proposer: &[u8; 20]) -> Vec<u8> {
+    let header = axon_header(proposer, 1000, &[0u8; 32]);
+    let signature = bls_aggregate_sign(&[bls_keypair(seed).0], &keccak256(&header));
+    rlp_list(&[header, rlp_bytes(&signature)])
+}
+
+#[test]
+fn test_stake_slash_success() {
+    // 30 of the 100 AT staked is burned, and the rest is released to the Withdrawal AT cell
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone()],
+            vec![staked, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_slash_unstaked_node() {
+    // node has unstaked all 100 AT from era 3, which is under unbonding in its Withdrawal AT cell
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let unstaked = scene.stake_info(3, 0);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone(), unstaked],
+            vec![staked, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(100, 11),
+        )
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .witness(2, input_type_witness(&[3]))
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_slash_unbonding_withdrawal_missing() {
+    // node has unstaked all 100 AT from era 3, whose Withdrawal AT cell is kept out of the slash
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let unstaked = scene.stake_info(3, 0);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone(), unstaked],
+            vec![staked, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .output(&scene.at_output, sudt_data(0))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_UNBONDING_AT_NOT_SLASHED);
+}
+
+#[test]
+fn test_stake_slash_pending_stake() {
+    // node has scheduled to raise its stake to 150 AT from era 9, which is superseded by the slash
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let pending = scene.stake_info(9, 150);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone(), pending],
+            vec![staked, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(150))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(105, 32),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_BLS_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_slash_replay() {
+    // node has been slashed from era 3 by the same proof
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let stake_infos = vec![scene.stake_info(0, 100), scene.slashed_stake_info(3)];
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            stake_infos.clone(),
+            stake_infos,
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(0))
+        .input(
+            &mut context,
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 11),
+        )
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(49, 32),
+        )
+        .witness(2, input_type_witness(&[3]))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SLASH_TARGET);
+}
+
+#[test]
+fn test_stake_slash_inactive_node() {
+    // node is cut off from the validator set of quorum_size 1 by another node staking 200 AT, so
+    // its votes never count
+    let mut context = Context::default();
+    let mut scene = StakeSlashScene::new(&mut context);
+    scene.quorum_size = 1;
+    let staked = scene.stake_info(0, 100);
+    let validator = axon_validator_info(&[9u8; 20], &[6u8; 20], &bls_keypair(2).1, 200, 0);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone(), validator.clone()],
+            vec![staked, validator, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(300))
+        .output(&scene.at_output, sudt_data(200))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SLASH_TARGET);
+}
+
+#[test]
+fn test_stake_slash_bad_proof() {
+    // both votes are for the same block
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let vote = slash_vote(1, &[1u8; 20]);
+    let staked = scene.stake_info(0, 100);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone()],
+            vec![staked, slashed],
+            rlp_list(&[vote.clone(), vote]),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_SLASH_PROOF);
+}
+
+#[test]
+fn test_stake_slash_malformed_proof() {
+    // a single vote instead of a list of two votes
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone()],
+            vec![staked, slashed],
+            rlp_list(&[rlp_list(&[])]),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_BAD_SLASH_PROOF);
+}
+
+#[test]
+fn test_stake_slash_not_burned() {
+    // the slashed 30 AT is kept in an output cell
+    let mut context = Context::default();
+    let scene = StakeSlashScene::new(&mut context);
+    let staked = scene.stake_info(0, 100);
+    let slashed = scene.slashed_stake_info(3);
+    let tx = scene
+        .slash_tx(
+            &mut context,
+            vec![staked.clone()],
+            vec![staked, slashed],
+            scene.proof.clone(),
+        )
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(0))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(70, 32),
+        )
+        .output(&scene.free_output, sudt_data(30))
+        .build(&scene.deployment);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_BLS_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_SLASHED_AT_NOT_BURNED);
+}
+
+// stake cell of a validator staking 100 AT in era 0, to which delegator appends its
+// delegate_info in delegate mode, and the AT cell under stake lock holds the stake of both
+struct StakeDelegateScene {
+    deployment: Deployment,
+    delegator_keypair: (Privkey, Pubkey),
+    validator_identity: axon::Identity,
+    stake_data: axon::StakeLockCellData,
+    companion_output: CellOutput,
+    stake_output: CellOutput,
+    at_output: CellOutput,
+    // Withdrawal AT cell of delegator, which receives undelegated AT
+    withdrawal_output: CellOutput,
+}
+
+impl StakeDelegateScene {
+    fn new(context: &mut Context) -> Self {
+        // withdrawal lock is referenced by the type hash of its code, as checkpoint lock builds it
+        let mut deployment = Deployment::new(context, "stake");
+        let withdrawal_lock_code_hash = deployment.deploy_by_type(context, "withdrawal", &[4]);
+        let type_id_type_script = deployment.always_success_script(context, &[]);
+        let at_type_script = deployment.always_success_script(context, &[2]);
+        let checkpoint_type_script = deployment.always_success_script(context, &[3]);
+
+        // prepare stake_args of both stake cell and companion cell of delegator
+        let admin_keypair = Generator::random_keypair();
+        let delegator_keypair = Generator::random_keypair();
+        let stake_args = axon::StakeLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .type_id_hash(axon_byte32(&type_id_type_script.calc_script_hash()))
+            .build();
+        let companion_args = stake_args
+            .clone()
+            .as_builder()
+            .node_identity(axon_identity_opt(&delegator_keypair.1))
+            .build();
+        let stake_lock_script = deployment.script(context, stake_args.as_bytes());
+        let companion_lock_script = deployment.script(context, companion_args.as_bytes());
+
+        // prepare stake_data of validator
+        let validator_keypair = Generator::random_keypair();
+        let validator_stake_info = axon_stake_info(&blake160(&validator_keypair.1.serialize()), 0)
+            .as_builder()
+            .stake_amount(axon_byte16(100))
+            .build();
+        let stake_data = axon_stake_data(
+            70,
+            &checkpoint_type_script.calc_script_hash(),
+            vec![validator_stake_info],
+        )
+        .as_builder()
+        .sudt_type_hash(axon_byte32(&at_type_script.calc_script_hash()))
+        .build();
+
+        // prepare checkpoint cell_dep in era 0
+        let checkpoint_data = axon_checkpoint_data(0, 1, &Byte32::default())
+            .as_builder()
+            .era_period(axon_byte4(10))
+            .unlock_period(axon_byte4(2))
+            .withdrawal_lock_code_hash(axon_byte32(&withdrawal_lock_code_hash))
+            .build();
+        deployment.add_cell_dep(context, &checkpoint_type_script, checkpoint_data.as_bytes());
+
+        // prepare withdrawal_args of delegator
+        let withdrawal_args = axon::WithdrawalLockArgs::new_builder()
+            .admin_identity(axon_identity(&admin_keypair.1))
+            .checkpoint_cell_type_hash(axon_byte32(&checkpoint_type_script.calc_script_hash()))
+            .node_identity(axon_identity_opt(&delegator_keypair.1))
+            .build();
+        let withdrawal_lock_script = Script::new_builder()
+            .code_hash(withdrawal_lock_code_hash)
+            .hash_type(ScriptHashType::Type.into())
+            .args(withdrawal_args.as_bytes().pack())
+            .build();
+
+        StakeDelegateScene {
+            deployment,
+            delegator_keypair,
+            validator_identity: axon_identity(&validator_keypair.1),
+            stake_data,
+            companion_output: cell_output(&companion_lock_script, Some(&at_type_script)),
+            stake_output: cell_output(&stake_lock_script, Some(&type_id_type_script)),
+            at_output: cell_output(&stake_lock_script, Some(&at_type_script)),
+            withdrawal_output: cell_output(&withdrawal_lock_script, Some(&at_type_script)),
+        }
+    }
+
+    // delegate_info of delegator delegating `amount` to validator from `era`
+    fn delegate_info(&self, era: u64, amount: u128) -> axon::DelegateInfo {
+        axon::DelegateInfo::new_builder()
+            .delegator_identity(axon_identity(&self.delegator_keypair.1))
+            .validator_identity(self.validator_identity.clone())
+            .amount(axon_byte16(amount))
+            .inauguration_era(axon_byte8(era))
+            .build()
+    }
+
+    fn stake_data(&self, delegate_infos: Vec<axon::DelegateInfo>) -> axon::StakeLockCellData {
+        self.stake_data
+            .clone()
+            .as_builder()
+            .delegate_infos(
+                axon::DelegateInfoVec::new_builder()
+                    .set(delegate_infos)
+                    .build(),
+            )
+            .build()
+    }
+
+    // transaction updating delegate_infos of the stake cell from `input_delegate_infos` to
+    // `output_delegate_infos` in delegate mode, to which the AT cells under stake lock are added
+    fn delegate_tx(
+        &self,
+        context: &mut Context,
+        input_delegate_infos: Vec<axon::DelegateInfo>,
+        output_delegate_infos: Vec<axon::DelegateInfo>,
+    ) -> TestTx {
+        TestTx::default()
+            .input(context, &self.companion_output, Bytes::new())
+            .input(
+                context,
+                &self.stake_output,
+                self.stake_data(input_delegate_infos).as_bytes(),
+            )
+            .output(&self.companion_output, Bytes::new())
+            .output(
+                &self.stake_output,
+                self.stake_data(output_delegate_infos).as_bytes(),
+            )
+            .witness(1, input_type_witness(&[4]))
+    }
+}
+
+#[test]
+fn test_stake_delegate_success() {
+    let mut context = Context::default();
+    let scene = StakeDelegateScene::new(&mut context);
+    let delegate_info = scene.delegate_info(2, 100);
+    let tx = scene
+        .delegate_tx(&mut context, vec![], vec![delegate_info])
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.delegator_keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_delegate_before_undelegate() {
+    // delegator has undelegated all 100 AT from era 5, and can not delegate 100 AT from era 2
+    // against the undelegated amount
+    let mut context = Context::default();
+    let scene = StakeDelegateScene::new(&mut context);
+    let scheduled = vec![scene.delegate_info(0, 100), scene.delegate_info(5, 0)];
+    let mut delegate_infos = scheduled.clone();
+    delegate_infos.push(scene.delegate_info(2, 100));
+    let tx = scene
+        .delegate_tx(&mut context, scheduled, delegate_infos)
+        .input(&mut context, &scene.at_output, sudt_data(100))
+        .output(&scene.at_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.delegator_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_INAUGURATION_ERA);
+}
+
+#[test]
+fn test_stake_undelegate_success() {
+    // undelegating 60 of 100 AT from era 2 releases 60 AT to the Withdrawal AT cell of delegator,
+    // which can not be unlocked until unlock_period of 2 after period 20, the first one of era 2
+    let mut context = Context::default();
+    let scene = StakeDelegateScene::new(&mut context);
+    let delegated = scene.delegate_info(0, 100);
+    let undelegated = scene.delegate_info(2, 40);
+    let tx = scene
+        .delegate_tx(
+            &mut context,
+            vec![delegated.clone()],
+            vec![delegated, undelegated],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(200))
+        .output(&scene.at_output, sudt_data(140))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(60, 22),
+        )
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.delegator_keypair.0, 1);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_undelegate_withdrawal_period() {
+    // the released 60 AT could be unlocked one period earlier than its unbonding ends
+    let mut context = Context::default();
+    let scene = StakeDelegateScene::new(&mut context);
+    let delegated = scene.delegate_info(0, 100);
+    let undelegated = scene.delegate_info(2, 40);
+    let tx = scene
+        .delegate_tx(
+            &mut context,
+            vec![delegated.clone()],
+            vec![delegated, undelegated],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(200))
+        .output(&scene.at_output, sudt_data(140))
+        .output(
+            &scene.withdrawal_output,
+            axon_withdrawal_amount_data(60, 21),
+        )
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.delegator_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_WITHDRAWAL_PERIOD);
+}
+
+#[test]
+fn test_stake_undelegate_applying_era() {
+    // undelegation from era 1 changes the validator set which has been applying, even though
+    // unbonding_eras is zero
+    let mut context = Context::default();
+    let scene = StakeDelegateScene::new(&mut context);
+    let delegated = scene.delegate_info(0, 100);
+    let undelegated = scene.delegate_info(1, 0);
+    let tx = scene
+        .delegate_tx(
+            &mut context,
+            vec![delegated.clone()],
+            vec![delegated, undelegated],
+        )
+        .input(&mut context, &scene.at_output, sudt_data(200))
+        .output(&scene.at_output, sudt_data(200))
+        .build(&scene.deployment);
+    let tx = sign_tx(tx, &scene.delegator_keypair.0, 1);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, ERROR_STAKE_BAD_INAUGURATION_ERA);
+}